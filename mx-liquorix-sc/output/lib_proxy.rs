@@ -248,6 +248,26 @@ where
             .original_result()
     }
 
+    /// Sets the maximum value a bot action can lose to swaps and fees. 
+    ///  
+    /// # Arguments 
+    /// - `max_slippage_bps` - Maximum equity lost in BPS of the traded amount 
+    ///  
+    /// # Validation 
+    /// - `max_slippage_bps` must be <= `MAX_SLIPPAGE_BPS` 
+    pub fn set_max_slippage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_slippage_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxSlippage")
+            .argument(&max_slippage_bps)
+            .original_result()
+    }
+
     /// Returns complete system information as a `SystemInfo` struct. 
     pub fn get_system_info(
         self,
@@ -261,13 +281,23 @@ where
     /// Returns system info as a MultiValue tuple for ABI compatibility. 
     pub fn get_system_info_multi_value(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue14<bool, EsdtTokenIdentifier<Env::Api>, ManagedDecimal<Env::Api, usize>, EsdtTokenIdentifier<Env::Api>, EsdtTokenIdentifier<Env::Api>, EsdtTokenIdentifier<Env::Api>, u64, bool, bool, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue16<bool, EsdtTokenIdentifier<Env::Api>, ManagedDecimal<Env::Api, usize>, EsdtTokenIdentifier<Env::Api>, EsdtTokenIdentifier<Env::Api>, EsdtTokenIdentifier<Env::Api>, u64, bool, bool, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getSystemInfoMultiValue")
             .original_result()
     }
 
+    /// Returns the maximum value a bot action can lose in BPS of the traded amount. 
+    pub fn get_max_slippage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxSlippage")
+            .original_result()
+    }
+
     /// Adds one or more addresses as admins. 
     ///  
     /// # Access Control 
@@ -308,6 +338,100 @@ where
             .original_result()
     }
 
+    /// Enables or disables the deposit allowlist. 
+    ///  
+    /// # Access Control 
+    /// Only callable by an admin. 
+    ///  
+    /// # Arguments 
+    /// - `enabled` - Whether deposits are restricted to allowlisted addresses 
+    pub fn set_allowlist_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAllowlistEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Adds one or more addresses to the allowlist. 
+    ///  
+    /// # Access Control 
+    /// Only callable by an admin. 
+    ///  
+    /// # Arguments 
+    /// - `addresses` - List of addresses to allow 
+    pub fn add_to_allowlist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        addresses: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToAllowlist")
+            .argument(&addresses)
+            .original_result()
+    }
+
+    /// Removes one or more addresses from the allowlist. 
+    ///  
+    /// # Access Control 
+    /// Only callable by an admin. 
+    ///  
+    /// # Arguments 
+    /// - `addresses` - List of addresses to remove 
+    pub fn remove_from_allowlist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        addresses: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromAllowlist")
+            .argument(&addresses)
+            .original_result()
+    }
+
+    /// Returns whether deposits are restricted to allowlisted addresses. 
+    pub fn is_allowlist_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAllowlistEnabled")
+            .original_result()
+    }
+
+    /// Returns whether the address can deposit under the current allowlist settings. 
+    pub fn can_deposit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canDeposit")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Returns all allowlisted addresses. 
+    pub fn get_allowlist(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllowlist")
+            .original_result()
+    }
+
     /// Pauses the contract. 
     ///  
     /// # Access Control 
@@ -343,32 +467,79 @@ where
     /// Deposits EGLD or xEGLD tokens into the vault. 
     ///  
     /// When EGLD is deposited, it is first delegated to liquid staking to receive xEGLD. 
+    /// Any other ESDT is first swapped to EGLD through the swap router and then delegated. 
     /// The xEGLD is then supplied to the lending protocol as collateral. 
     /// The user receives share tokens proportional to their contribution. 
     ///  
     /// ## Share Calculation 
-    /// - First deposit: shares = deposit_amount (1:1 ratio) 
+    /// - First deposit: shares = deposit_amount - DEAD_SHARES (1:1 ratio). The first deposit 
+    ///   must be at least `MIN_FIRST_DEPOSIT_AMOUNT`, and `DEAD_SHARES` are minted to the 
+    ///   contract and locked forever, so the share supply never returns to zero 
     /// - Subsequent deposits: `shares = (total_shares * deposit_egld_value) / vault_equity` 
     ///   where `vault_equity = total_collateral_in_egld - total_debt_in_egld` 
+    /// - The management and performance fees are charged before pricing, and the 
+    ///   deposit fee is taken from the minted shares 
+    ///  
+    /// ## Deposit Caps 
+    /// The vault equity after the deposit must stay within `max_vault_equity_in_egld`, 
+    /// and the cumulative EGLD value credited to the recipient within 
+    /// `max_deposit_per_address`. The per-address cap is deposit-only and is not 
+    /// released on withdraw. Deposits exceeding a cap are reverted. 
+    ///  
+    /// ## Arguments 
+    /// - `opt_min_shares_out` - Optional minimum amount of shares to mint for this deposit 
+    /// - `opt_swap_args` - Swap router arguments for swapping the deposited token to EGLD. 
+    ///   Required when depositing a token other than EGLD or xEGLD. 
+    /// - `opt_recipient` - Optional address receiving the share token instead of the caller. 
+    ///   The allowlist applies to both the caller and the recipient, the per-address deposit 
+    ///   cap to the recipient. Arguments are positional, so pass `0` and an empty list for 
+    ///   the previous arguments when not needed. 
     ///  
     /// ## Payment 
     /// - EGLD: Will be converted to xEGLD via liquid staking 
     /// - xEGLD (supplied_token): Will be directly supplied to lending 
+    /// - Any other ESDT: Will be swapped to EGLD, then converted to xEGLD via liquid staking. 
+    ///   Shares are computed on the received xEGLD, not on the input token. 
+    /// - Optional second payment: an existing share token to be merged with the new position. 
+    ///   Only accepted when the caller is the recipient. 
     ///  
     /// ## Returns 
     /// Share token (DynamicMeta NFT) representing the user's share of the vault. 
     /// The NFT attributes store the snapshot of total shares and supplied amount 
     /// at the time of deposit for PnL calculations. 
     ///  
+    /// When a share token is sent along with the deposit, it is burned and a single 
+    /// merged share token is returned, holding both the existing and the newly minted shares. 
+    /// The merged attributes sum the share supply and initial supplied amount and 
+    /// keep the latest interaction timestamp. 
+    ///  
     /// ## Panics 
     /// - If the contract is paused 
+    /// - If the allowlist is enabled and the caller or the recipient is not allowlisted 
     /// - If the deposit amount is zero 
-    /// - If the token is not EGLD or the configured supplied_token (xEGLD) 
-    pub fn deposit(
+    /// - If the vault is empty and the deposit is below `MIN_FIRST_DEPOSIT_AMOUNT` 
+    /// - If the token is not EGLD or the configured supplied_token (xEGLD) and no swap args are given 
+    /// - If the swap returns no EGLD 
+    /// - If the second payment is not a valid share token 
+    /// - If a share token is sent and the recipient is not the caller 
+    /// - If fewer shares than `opt_min_shares_out` are minted 
+    /// - If the deposit exceeds the vault equity cap or the recipient's deposit cap 
+    /// - If the lending position ends below the minimum health factor 
+    pub fn deposit<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
         self,
+        opt_min_shares_out: Arg0,
+        opt_swap_args: Arg1,
+        opt_recipient: Arg2,
     ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
         self.wrapped_tx
             .raw_call("deposit")
+            .argument(&opt_min_shares_out)
+            .argument(&opt_swap_args)
+            .argument(&opt_recipient)
             .original_result()
     }
 
@@ -377,7 +548,13 @@ where
     /// Calculates the proportional share of collateral and debt based on 
     /// the share tokens provided. If the vault has outstanding debt, the user's 
     /// share of debt is first repaid using their collateral share, and then 
-    /// the remaining collateral is withdrawn and transferred to the caller. 
+    /// the remaining collateral is withdrawn and transferred to the receiver. 
+    ///  
+    /// Share tokens withdrawn during their holding period are charged the early exit 
+    /// penalty, or rejected when no penalty is set. 
+    ///  
+    /// Several share token nonces can be redeemed at once: their amounts are summed, 
+    /// so the debt is repaid and the collateral withdrawn in a single lending round-trip. 
     ///  
     /// ## Withdrawal Logic 
     /// 1. Calculate user's share of total collateral 
@@ -387,28 +564,289 @@ where
     ///  
     /// # Arguments 
     /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral. 
-    ///   Required when the vault has outstanding debt. 
+    ///   Required when the vault has outstanding debt. Pass an empty list when 
+    ///   only `opt_min_amount_out` or `opt_receiver` is needed. 
+    /// - `opt_min_amount_out` - Optional minimum amount of xEGLD to receive 
+    /// - `opt_receiver` - Optional address receiving the xEGLD instead of the caller. 
+    ///   Pass `0` as `opt_min_amount_out` when no minimum is needed. 
     ///  
     /// # Payment 
-    /// Share token to be burned for withdrawal. 
+    /// One or more share tokens (any nonces) to be burned for withdrawal. 
     ///  
     /// # Transfers 
-    /// Sends the withdrawn collateral tokens (xEGLD) directly to the caller. 
+    /// Sends the withdrawn collateral tokens (xEGLD) directly to the receiver, 
+    /// or to the caller if no receiver is given. 
     ///  
     /// # Panics 
     /// - Panics if the contract is paused 
     /// - Panics if the share token payment is invalid 
+    /// - Panics if a share token is in its holding period and no early exit penalty is set 
     /// - Panics if the calculated withdrawal amount is zero 
     /// - Panics if collateral is insufficient to cover the debt share 
+    /// - Panics if the lending position ends below the minimum health factor 
+    /// - Panics if the withdrawn amount is below `opt_min_amount_out` 
     pub fn withdraw<
         Arg0: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         swap_steps: Arg0,
+        opt_min_amount_out: Arg1,
+        opt_receiver: Arg2,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("withdraw")
             .argument(&swap_steps)
+            .argument(&opt_min_amount_out)
+            .argument(&opt_receiver)
+            .original_result()
+    }
+
+    /// Withdraws from the vault and unstakes the withdrawn xEGLD. 
+    ///  
+    /// Runs the same withdrawal logic as `withdraw`, but instead of sending 
+    /// xEGLD, the withdrawn amount is un-delegated through the liquid staking 
+    /// contract. Everything returned by the liquid staking contract (the unstake 
+    /// NFT, and any EGLD that could be unstaked instantly) is forwarded to the caller. 
+    /// The caller claims the EGLD from the liquid staking contract once the 
+    /// unbond period has passed. 
+    ///  
+    /// # Arguments 
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral. 
+    ///   Required when the vault has outstanding debt. 
+    ///  
+    /// # Payment 
+    /// Share token to be burned for withdrawal. 
+    ///  
+    /// # Transfers 
+    /// Sends the payments returned by the liquid staking `unDelegate` call to the caller. 
+    ///  
+    /// # Panics 
+    /// - Panics if the contract is paused 
+    /// - Panics if the share token payment is invalid 
+    /// - Panics if a share token is in its holding period and no early exit penalty is set 
+    /// - Panics if the calculated withdrawal amount is zero 
+    /// - Panics if collateral is insufficient to cover the debt share 
+    /// - Panics if the lending position ends below the minimum health factor 
+    pub fn withdraw_and_unstake<
+        Arg0: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        swap_steps: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawAndUnstake")
+            .argument(&swap_steps)
+            .original_result()
+    }
+
+    /// Withdraws from the vault and swaps the withdrawn xEGLD to EGLD. 
+    ///  
+    /// Runs the same withdrawal logic as `withdraw`, then swaps the withdrawn 
+    /// xEGLD to EGLD through the swap router and sends native EGLD to the caller. 
+    /// Any tokens refunded by the swap router are sent to the caller as well. 
+    ///  
+    /// # Arguments 
+    /// - `min_amount_out` - Minimum amount of EGLD the caller accepts from the swap 
+    /// - `swap_args` - Swap router arguments for swapping xEGLD to EGLD 
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral. 
+    ///   Required when the vault has outstanding debt. 
+    ///  
+    /// # Payment 
+    /// Share token to be burned for withdrawal. 
+    ///  
+    /// # Transfers 
+    /// Sends the swapped EGLD to the caller. 
+    ///  
+    /// # Panics 
+    /// - Panics if the contract is paused 
+    /// - Panics if the share token payment is invalid 
+    /// - Panics if a share token is in its holding period and no early exit penalty is set 
+    /// - Panics if the calculated withdrawal amount is zero 
+    /// - Panics if collateral is insufficient to cover the debt share 
+    /// - Panics if the lending position ends below the minimum health factor 
+    /// - Panics if the swap returns less than `min_amount_out` 
+    pub fn withdraw_and_swap<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        min_amount_out: Arg0,
+        swap_args: Arg1,
+        swap_steps: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawAndSwap")
+            .argument(&min_amount_out)
+            .argument(&swap_args)
+            .argument(&swap_steps)
+            .original_result()
+    }
+
+    /// Withdraws an exact net amount of xEGLD from the vault. 
+    ///  
+    /// Calculates the share tokens needed to receive `amount` after the debt 
+    /// repayment and the withdraw fee, redeems only those shares and sends the 
+    /// leftover share tokens back to the caller with their updated attributes. 
+    /// Any collateral left over by rounding stays in the vault. 
+    ///  
+    /// # Arguments 
+    /// - `amount` - Net amount of supplied token (xEGLD) to receive 
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral. 
+    ///   Required when the vault has outstanding debt. 
+    ///  
+    /// # Payment 
+    /// Share token covering at least the shares needed for `amount`. 
+    ///  
+    /// # Transfers 
+    /// Sends exactly `amount` xEGLD and the unused share tokens to the caller. 
+    ///  
+    /// # Panics 
+    /// - Panics if the contract is paused 
+    /// - Panics if the share token payment is invalid 
+    /// - Panics if `amount` is zero 
+    /// - Panics if the share token is in its holding period, even with an early exit penalty 
+    /// - Panics if the share token payment does not cover the shares needed for `amount` 
+    /// - Panics if collateral is insufficient to cover the debt share 
+    /// - Panics if the lending position ends below the minimum health factor 
+    pub fn withdraw_exact<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        amount: Arg0,
+        swap_steps: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawExact")
+            .argument(&amount)
+            .argument(&swap_steps)
+            .original_result()
+    }
+
+    /// Withdraws from the vault by repaying the debt share with borrowed tokens. 
+    ///  
+    /// Instead of swapping collateral to repay the debt, the caller sends the 
+    /// borrowed token covering the debt share of the redeemed shares. The vault 
+    /// repays it to the lending position and pays out the full collateral share, 
+    /// so no swap slippage is incurred. 
+    ///  
+    /// The debt share is rounded up, so the remaining holders never carry more 
+    /// debt than before. Any borrowed tokens sent above the debt share are refunded. 
+    ///  
+    /// # Payment 
+    /// - One or more share tokens (any nonces) to be burned for withdrawal 
+    /// - The borrowed token covering the debt share (not needed without debt) 
+    ///  
+    /// # Transfers 
+    /// Sends the collateral share (xEGLD) and any excess borrowed tokens to the caller. 
+    ///  
+    /// # Panics 
+    /// - Panics if the contract is paused 
+    /// - Panics if any other payment is not a valid share token 
+    /// - Panics if a share token is in its holding period and no early exit penalty is set 
+    /// - Panics if more than one borrowed token payment is received 
+    /// - Panics if the calculated withdrawal amount is zero 
+    /// - Panics if the borrowed token payment does not cover the debt share 
+    /// - Panics if the lending position ends below the minimum health factor 
+    pub fn withdraw_in_kind(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawInKind")
+            .original_result()
+    }
+
+    /// Exits the vault while the contract is paused, without any swap. 
+    ///  
+    /// The user's pro-rata collateral is withdrawn in one of two ways: 
+    /// - If borrowed tokens are sent, they repay the debt share as in `withdrawInKind` 
+    ///   and the full collateral share is paid out 
+    /// - Otherwise, the debt share stays with the vault and the collateral share is 
+    ///   reduced by the debt share valued at the lending oracle price 
+    ///  
+    /// Either way, the lending position must stay at or above the minimum health factor. 
+    ///  
+    /// Only the management fee is crystallized, which needs no external call. 
+    /// The performance and withdraw fees, and the holding period, do not apply 
+    /// to an emergency exit. 
+    ///  
+    /// # Payment 
+    /// - One or more share tokens (any nonces) to be burned for withdrawal 
+    /// - Optionally, the borrowed token covering the debt share 
+    ///  
+    /// # Transfers 
+    /// Sends the withdrawn collateral (xEGLD) and any excess borrowed tokens to the caller. 
+    ///  
+    /// # Panics 
+    /// - Panics if the contract is not paused 
+    /// - Panics if any other payment is not a valid share token 
+    /// - Panics if more than one borrowed token payment is received 
+    /// - Panics if the calculated withdrawal amount is zero 
+    /// - Panics if the borrowed token payment does not cover the debt share 
+    /// - Panics if the collateral share does not cover the debt share 
+    /// - Panics if the lending position ends below the minimum health factor 
+    pub fn emergency_withdraw(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("emergencyWithdraw")
+            .original_result()
+    }
+
+    /// Merges several share tokens into a single share token. 
+    ///  
+    /// All received share tokens are burned and one new share token is minted, 
+    /// holding the sum of their shares. The lending position is not touched. 
+    ///  
+    /// # Payment 
+    /// Two or more share token payments (any nonces). 
+    ///  
+    /// # Returns 
+    /// The merged share token. Its attributes sum the share supply and initial 
+    /// supplied amount of all payments and keep the latest timestamp. 
+    ///  
+    /// # Panics 
+    /// - Panics if fewer than two payments are received 
+    /// - Panics if any payment is not a valid share token 
+    pub fn merge_shares(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("mergeShares")
+            .original_result()
+    }
+
+    /// Splits a share token into several share tokens. 
+    ///  
+    /// The received share token is burned and one new share token is minted 
+    /// for each requested amount. The initial supplied amount is distributed 
+    /// proportionally, and every new token keeps the original timestamp. 
+    /// The lending position is not touched. 
+    ///  
+    /// # Arguments 
+    /// - `amounts` - Share amounts of the new tokens; must sum up to the payment amount 
+    ///  
+    /// # Payment 
+    /// A single share token. 
+    ///  
+    /// # Returns 
+    /// The newly created share tokens, in the order of `amounts`. 
+    ///  
+    /// # Panics 
+    /// - Panics if the share token payment is invalid 
+    /// - Panics if any amount is zero or the amounts do not sum up to the payment amount 
+    pub fn split_shares<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, BigUint<Env::Api>>>,
+    >(
+        self,
+        amounts: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValueEncoded<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("splitShares")
+            .argument(&amounts)
             .original_result()
     }
 
@@ -444,13 +882,12 @@ where
             .original_result()
     }
 
-    /// Returns the PnL (Profit and Loss) for a list of share token positions. 
+    /// Returns the PnL (Profit and Loss) of each share token position held by an address. 
     ///  
-    /// Given an address and a list of share token nonces, calculates: 
-    /// - For each token: share_initial_value and share_current_value 
-    /// - Returns tuple of (pnl, pnl_percentage) where: 
-    ///   - pnl = total_share_current_value - total_share_initial_value 
-    ///   - pnl_percentage = pnl * 100 / total_share_initial_value 
+    /// The PnL is computed on the vault equity (collateral minus debt) after the 
+    /// pending management and performance fees, and reported in both EGLD and USD. 
+    /// The initial value is the initially supplied xEGLD at the current xEGLD price, 
+    /// so the PnL is measured against simply holding that xEGLD. 
     ///  
     /// # Validation 
     /// - Skips any share tokens that do not have a balance for the given address 
@@ -461,7 +898,7 @@ where
     /// - `nonces` - List of share token nonces to calculate PnL for 
     ///  
     /// # Returns 
-    /// The total PnL as a ManagedDecimal. 
+    /// A `PositionPnl` breakdown for each held nonce, in the order of `nonces`. 
     pub fn get_positions_pnl<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
@@ -469,7 +906,7 @@ where
         self,
         address: Arg0,
         nonces: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PositionPnl<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getPositionsPnl")
@@ -478,34 +915,242 @@ where
             .original_result()
     }
 
-    /// Increases the leverage of the vault position. 
+    /// Returns the share token positions registered for an address. 
     ///  
-    /// This endpoint borrows additional tokens and supplies them as collateral 
-    /// to increase the overall yield. 
+    /// Nonces are registered when share tokens are minted or sent to the vault 
+    /// (see `syncAccountPositions` for share tokens received through a direct transfer). 
+    /// Each position has its attributes and the same PnL breakdown as `getPositionsPnl`. 
+    ///  
+    /// # Validation 
+    /// - Skips any registered share tokens that the address no longer holds 
+    /// or the address is from another shard. 
     ///  
     /// # Arguments 
-    /// - `amount_to_borrow` - Amount of the borrowed token to borrow 
-    /// - `decimals` - Number of decimals for the borrowed token 
-    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD) 
+    /// - `address` - The address holding the share tokens 
     ///  
-    /// # Access Control 
-    /// Only callable by the authorized bot address. 
-    pub fn leverage<
-        Arg0: ProxyArg<BigUint<Env::Api>>,
-        Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    /// # Returns 
+    /// An `AccountPosition` for each held nonce. 
+    pub fn get_account_positions<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        borrowed_amount: Arg0,
-        decimals: Arg1,
-        swap_args: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, AccountPosition<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountPositions")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Previews the share tokens minted by a deposit. 
+    ///  
+    /// Runs the same calculations as `deposit`, including the pending management 
+    /// and performance fees and the deposit fee, without changing any state. 
+    /// EGLD is converted to xEGLD and xEGLD is valued through the same readonly 
+    /// liquid staking queries as `deposit`. 
+    ///  
+    /// # Arguments 
+    /// - `token` - EGLD or the supplied token (xEGLD) 
+    /// - `amount` - Amount of the token to deposit 
+    ///  
+    /// # Returns 
+    /// The shares the depositor would receive. 
+    ///  
+    /// # Panics 
+    /// - Panics if the amount is zero 
+    /// - Panics if the token is not EGLD or the supplied token (xEGLD) 
+    /// - Panics if the vault is empty and the deposit is below `MIN_FIRST_DEPOSIT_AMOUNT` 
+    /// - Panics if no shares would be minted 
+    ///  
+    /// The allowlist, the deposit caps and the minimum health factor depend on the 
+    /// depositor and the executed deposit, so only `deposit` checks them. 
+    pub fn preview_deposit<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewDeposit")
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Previews redeeming an amount of share tokens. 
+    ///  
+    /// Runs the same calculations as the withdraw endpoints, including the pending 
+    /// management and performance fees, the withdraw fee and the early exit penalty, 
+    /// without changing any state. 
+    ///  
+    /// # Arguments 
+    /// - `share_amount` - Amount of share tokens to redeem 
+    /// - `opt_share_token` - Optional holder address and nonce of the share token redeemed, 
+    ///   to apply the early exit penalty while it is in its holding period 
+    ///  
+    /// # Returns 
+    /// The shares redeemed after the fee and the penalty, the collateral withdrawn, 
+    /// the debt repaid and the net xEGLD paid out, as a `WithdrawAmounts` struct. 
+    ///  
+    /// # Panics 
+    /// Panics if the share token is in its holding period and no early exit penalty 
+    /// is configured. 
+    pub fn preview_redeem<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<MultiValue2<ManagedAddress<Env::Api>, u64>>>,
+    >(
+        self,
+        share_amount: Arg0,
+        opt_share_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WithdrawAmounts<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewRedeem")
+            .argument(&share_amount)
+            .argument(&opt_share_token)
+            .original_result()
+    }
+
+    /// Previews the share tokens needed to withdraw a net amount of xEGLD. 
+    ///  
+    /// Accounts for the debt repaid with collateral, the pending management and 
+    /// performance fees and the withdraw fee. The result is rounded up, so 
+    /// redeeming it pays out at least `amount`. 
+    ///  
+    /// # Arguments 
+    /// - `amount` - Net amount of supplied token (xEGLD) to receive 
+    ///  
+    /// # Returns 
+    /// The share amount to send. 
+    pub fn preview_withdraw<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewWithdraw")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Increases the leverage of the vault position. 
+    ///  
+    /// This endpoint borrows additional tokens and supplies them as collateral 
+    /// to increase the overall yield. 
+    ///  
+    /// # Arguments 
+    /// - `amount_to_borrow` - Amount of the borrowed token to borrow 
+    /// - `decimals` - Number of decimals for the borrowed token 
+    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD) 
+    /// - `opt_min_amount_out` - Optional minimum amount of EGLD to receive from the swap 
+    ///  
+    /// # Access Control 
+    /// Only callable by the authorized bot address. 
+    ///  
+    /// # Panics 
+    /// - Panics if the new LTV ratio is outside the suggested zone bounds 
+    /// - Panics if the swap returns less than `opt_min_amount_out` 
+    /// - Panics if the executed position fails the post-execution checks 
+    /// - Panics if the lending position ends below the minimum health factor 
+    pub fn leverage<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        borrowed_amount: Arg0,
+        decimals: Arg1,
+        swap_args: Arg2,
+        opt_min_amount_out: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("leverage")
             .argument(&borrowed_amount)
             .argument(&decimals)
             .argument(&swap_args)
+            .argument(&opt_min_amount_out)
+            .original_result()
+    }
+
+    /// Increases the leverage of the vault position up to a target LTV ratio in a single transaction. 
+    ///  
+    /// Flash-borrows the full adjustment amount reported by `getBotInfo` for the target, 
+    /// which `flashLoanLeverageCallback` swaps to EGLD, delegates to xEGLD and supplies, 
+    /// before borrowing against the new collateral to repay the flash loan and its fee. 
+    ///  
+    /// # Arguments 
+    /// - `target_ltv_ratio_bps` - Target LTV ratio in BPS 
+    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD) 
+    ///  
+    /// # Access Control 
+    /// Only callable by the authorized bot address. 
+    ///  
+    /// # Panics 
+    /// - Panics if the target LTV ratio is outside the suggested zone bounds 
+    /// - Panics if the current LTV ratio is already at or above the target 
+    /// - Panics if the executed position fails the post-execution checks 
+    /// - Panics if the lending position ends below the minimum health factor 
+    pub fn leverage_to_target<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        target_ltv_ratio_bps: Arg0,
+        swap_args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("leverageToTarget")
+            .argument(&target_ltv_ratio_bps)
+            .argument(&swap_args)
+            .original_result()
+    }
+
+    /// Receives the flash loan taken by `leverageToTarget` and leverages the vault position with it. 
+    ///  
+    /// Swaps the loan to EGLD, delegates it to xEGLD and supplies it, then borrows 
+    /// the loan plus the flash loan fee against the new collateral and sends it back. 
+    ///  
+    /// # Arguments 
+    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD) 
+    /// - `decimals` - Number of decimals for the borrowed token 
+    /// - `refunds_receiver` - Address receiving any swap refunds 
+    /// - `initiator` - Address that took the flash loan, appended by the lending protocol 
+    ///  
+    /// # Payment 
+    /// The flash-borrowed tokens. 
+    ///  
+    /// # Transfers 
+    /// Sends the loan plus the flash loan fee back to the caller. 
+    ///  
+    /// # Access Control 
+    /// Only callable by the lending controller during a flash loan taken by the vault. 
+    pub fn flash_loan_leverage_callback<
+        Arg0: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        swap_args: Arg0,
+        decimals: Arg1,
+        refunds_receiver: Arg2,
+        initiator: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("flashLoanLeverageCallback")
+            .argument(&swap_args)
+            .argument(&decimals)
+            .argument(&refunds_receiver)
+            .argument(&initiator)
             .original_result()
     }
 
@@ -515,25 +1160,217 @@ where
     /// Uses the lending protocol's built-in swap functionality to convert collateral 
     /// to borrowed token and repay debt in a single transaction. 
     ///  
+    /// While the lending position is below the minimum health factor, the new LTV 
+    /// ratio is not required to be within the suggested zone bounds. 
+    ///  
     /// # Arguments 
     /// - `collateral_amount` - Amount of the collateral token to use for repayment 
     /// - `swap_steps` - Steps for the internal swap operation (e.g. xEGLD -> USDC) 
+    /// - `opt_min_repaid_amount` - Optional minimum amount of the borrowed token to repay 
     ///  
     /// # Access Control 
     /// Only callable by the authorized bot address. 
+    ///  
+    /// # Panics 
+    /// - Panics if the new LTV ratio is outside the suggested zone bounds, unless the 
+    ///   position is below the minimum health factor 
+    /// - Panics if the repaid debt is below `opt_min_repaid_amount` 
+    /// - Panics if the executed position fails the post-execution checks 
     pub fn deleverage<
         Arg0: ProxyArg<BigUint<Env::Api>>,
         Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
         collateral_amount: Arg0,
         swap_steps: Arg1,
+        opt_min_repaid_amount: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("deleverage")
             .argument(&collateral_amount)
             .argument(&swap_steps)
+            .argument(&opt_min_repaid_amount)
+            .original_result()
+    }
+
+    /// Decreases the leverage of the vault position through a flash loan. 
+    ///  
+    /// Flash-borrows the debt token to repay debt, which `flashLoanDeleverageCallback` 
+    /// follows by withdrawing the freed collateral and swapping it back to repay the 
+    /// flash loan and its fee. An alternative to `deleverage` when repaying debt with 
+    /// collateral through the lending protocol is constrained. 
+    ///  
+    /// While the lending position is below the minimum health factor, the new LTV 
+    /// ratio is not required to be within the suggested zone bounds. 
+    ///  
+    /// # Arguments 
+    /// - `debt_amount` - Amount of the borrowed token to repay 
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap 
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC) 
+    /// - `opt_surplus_swap_args` - Swap route of the surplus to EGLD (e.g. USDC -> EGLD), 
+    ///   required when the whole debt is repaid and a surplus is left 
+    ///  
+    /// # Access Control 
+    /// Only callable by the authorized bot address. 
+    ///  
+    /// # Panics 
+    /// - Panics if the debt or collateral amount exceeds the position 
+    /// - Panics if the new LTV ratio is outside the suggested zone bounds, unless the 
+    ///   position is below the minimum health factor 
+    /// - Panics if the executed position fails the post-execution checks 
+    pub fn flash_deleverage<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        debt_amount: Arg0,
+        collateral_amount: Arg1,
+        swap_args: Arg2,
+        opt_surplus_swap_args: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flashDeleverage")
+            .argument(&debt_amount)
+            .argument(&collateral_amount)
+            .argument(&swap_args)
+            .argument(&opt_surplus_swap_args)
+            .original_result()
+    }
+
+    /// Repays all the debt of the vault position in a single transaction, for incident response. 
+    ///  
+    /// Flash-borrows the full debt and unwinds it as `flashDeleverage` does, without 
+    /// checking the resulting LTV ratio against the suggested zone bounds. The equity 
+    /// lost is still checked against the maximum slippage. 
+    ///  
+    /// # Arguments 
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap, 
+    ///   which must cover the debt and the flash loan fee 
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC) 
+    /// - `opt_surplus_swap_args` - Swap route of the surplus to EGLD (e.g. USDC -> EGLD), 
+    ///   required when a surplus is left 
+    ///  
+    /// # Access Control 
+    /// Only callable by an admin. 
+    ///  
+    /// # Panics 
+    /// - Panics if the vault has no debt 
+    /// - Panics if the collateral amount exceeds the position 
+    /// - Panics if the equity lost exceeds the maximum slippage 
+    pub fn unwind_all<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        collateral_amount: Arg0,
+        swap_args: Arg1,
+        opt_surplus_swap_args: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unwindAll")
+            .argument(&collateral_amount)
+            .argument(&swap_args)
+            .argument(&opt_surplus_swap_args)
+            .original_result()
+    }
+
+    /// Receives the flash loan taken by `flashDeleverage` or `unwindAll` and deleverages 
+    /// the vault position with it. 
+    ///  
+    /// Repays debt with the loan, withdraws the freed collateral and swaps it to the 
+    /// borrowed token, then sends back the loan plus the flash loan fee. Any swapped 
+    /// amount left repays more debt. Once the debt is fully repaid, it is swapped to 
+    /// EGLD, delegated and supplied as xEGLD, so that it stays in the vault equity and 
+    /// is paid out pro-rata by the withdrawals. 
+    ///  
+    /// # Arguments 
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap 
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC) 
+    /// - `surplus_swap_args` - Swap route of the surplus to EGLD (e.g. USDC -> EGLD), 
+    ///   empty when not provided 
+    /// - `decimals` - Number of decimals for the borrowed token 
+    /// - `refunds_receiver` - Address receiving any swap refunds 
+    /// - `initiator` - Address that took the flash loan, appended by the lending protocol 
+    ///  
+    /// # Payment 
+    /// The flash-borrowed tokens. 
+    ///  
+    /// # Transfers 
+    /// Sends the loan plus the flash loan fee back to the caller. 
+    ///  
+    /// # Access Control 
+    /// Only callable by the lending controller during a flash loan taken by the vault. 
+    ///  
+    /// # Panics 
+    /// - Panics if the swapped amount does not cover the loan plus the flash loan fee 
+    /// - Panics if a surplus is left once the debt is fully repaid and no surplus route is given 
+    pub fn flash_loan_deleverage_callback<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg5: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        collateral_amount: Arg0,
+        swap_args: Arg1,
+        surplus_swap_args: Arg2,
+        decimals: Arg3,
+        refunds_receiver: Arg4,
+        initiator: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("flashLoanDeleverageCallback")
+            .argument(&collateral_amount)
+            .argument(&swap_args)
+            .argument(&surplus_swap_args)
+            .argument(&decimals)
+            .argument(&refunds_receiver)
+            .argument(&initiator)
+            .original_result()
+    }
+
+    /// Rebalances the vault position to the safe threshold, callable by anyone. 
+    ///  
+    /// Only allowed when `getBotInfo` suggests to leverage or deleverage. Executes 
+    /// exactly the suggested adjustment, like `leverageToTarget` when leveraging, and 
+    /// by repaying debt with collateral through the lending protocol when deleveraging. 
+    /// The caller is paid the keeper reward for the adjustment, as a share token. 
+    ///  
+    /// While the lending position is below the minimum health factor, a deleverage 
+    /// skips the LTV zone bounds after execution, as in `deleverage`. 
+    ///  
+    /// The caller chooses the swap route, so the equity lost is bounded by the tighter 
+    /// `max_keeper_slippage_bps` instead of the `max_slippage_bps` of the bot actions. 
+    ///  
+    /// # Arguments 
+    /// - `swap_args` - Arguments for the swap operation: swap route for the flash loan 
+    ///   when leveraging (e.g. USDC -> EGLD), swap steps for the lending protocol when 
+    ///   deleveraging (e.g. xEGLD -> USDC) 
+    ///  
+    /// # Panics 
+    /// - Panics if the contract is paused 
+    /// - Panics if the LTV ratio is within the inaction zone 
+    /// - Panics if the executed position fails the post-execution checks 
+    /// - Panics if leveraging leaves the lending position below the minimum health factor 
+    pub fn rebalance<
+        Arg0: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        swap_args: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebalance")
+            .argument(&swap_args)
             .original_result()
     }
 
@@ -581,6 +1418,363 @@ where
             .argument(&opt_target_ltv_ratio_bps)
             .original_result()
     }
+
+    /// Sets the treasury address receiving the fee shares. 
+    pub fn set_treasury_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Sets the vault fees. 
+    ///  
+    /// # Arguments 
+    /// - `deposit_fee_bps` - Fee in BPS of the shares minted on deposit 
+    /// - `withdraw_fee_bps` - Fee in BPS of the shares redeemed on withdraw 
+    /// - `performance_fee_bps` - Fee in BPS of the share price gains above the high-water mark 
+    ///  
+    /// # Validation 
+    /// - `deposit_fee_bps` and `withdraw_fee_bps` must be <= `MAX_ENTRY_EXIT_FEE_BPS` 
+    /// - `performance_fee_bps` must be <= `MAX_PERFORMANCE_FEE_BPS` 
+    pub fn set_fees<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        deposit_fee_bps: Arg0,
+        withdraw_fee_bps: Arg1,
+        performance_fee_bps: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFees")
+            .argument(&deposit_fee_bps)
+            .argument(&withdraw_fee_bps)
+            .argument(&performance_fee_bps)
+            .original_result()
+    }
+
+    /// Sets the annualized management fee. 
+    ///  
+    /// The management fee accrued at the previous rate is crystallized first. 
+    ///  
+    /// # Arguments 
+    /// - `management_fee_bps` - Fee in BPS of the vault per year 
+    ///  
+    /// # Validation 
+    /// - `management_fee_bps` must be <= `MAX_MANAGEMENT_FEE_BPS` 
+    pub fn set_management_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        management_fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setManagementFee")
+            .argument(&management_fee_bps)
+            .original_result()
+    }
+
+    /// Claims the accrued fee shares. 
+    ///  
+    /// Mints a share token holding all accrued fee shares and sends it to the treasury. 
+    /// The shares are already part of `total_shares`, so the share price is unchanged. 
+    ///  
+    /// # Access Control 
+    /// Only callable by the treasury or the owner. 
+    ///  
+    /// # Returns 
+    /// The share token sent to the treasury. 
+    pub fn claim_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimFees")
+            .original_result()
+    }
+
+    /// Returns the fees in BPS as `(deposit_fee_bps, withdraw_fee_bps, performance_fee_bps)`. 
+    pub fn get_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFees")
+            .original_result()
+    }
+
+    /// Returns the management fee shares accrued since the last crystallization. 
+    ///  
+    /// These shares are not yet part of `total_shares`; they are minted on the 
+    /// next deposit, withdrawal or bot action. 
+    pub fn get_accrued_management_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccruedManagementFee")
+            .original_result()
+    }
+
+    /// Returns the management fee in BPS per year. 
+    pub fn get_management_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getManagementFee")
+            .original_result()
+    }
+
+    /// Returns the treasury address. 
+    pub fn get_treasury_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryAddress")
+            .original_result()
+    }
+
+    /// Sets the deposit caps. A value of zero disables the cap. 
+    ///  
+    /// # Arguments 
+    /// - `max_vault_equity_in_egld` - Maximum vault equity (collateral minus debt) in EGLD 
+    /// - `max_deposit_per_address` - Maximum cumulative deposit value per address in EGLD 
+    pub fn set_deposit_caps<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        max_vault_equity_in_egld: Arg0,
+        max_deposit_per_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDepositCaps")
+            .argument(&max_vault_equity_in_egld)
+            .argument(&max_deposit_per_address)
+            .original_result()
+    }
+
+    /// Returns the deposit caps and the remaining capacity for an address as 
+    /// `(max_vault_equity_in_egld, remaining_vault_capacity_in_egld, 
+    /// max_deposit_per_address, remaining_address_capacity)`. 
+    ///  
+    /// A cap of zero is disabled, and its remaining capacity is reported as zero. 
+    pub fn get_deposit_caps<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositCaps")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Returns the cumulative deposit value in EGLD credited to an address. 
+    pub fn get_deposited_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositedAmount")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Sets the minimum holding period and the early exit penalty. 
+    ///  
+    /// # Arguments 
+    /// - `min_holding_period_millis` - Minimum time between the last interaction of a 
+    ///   share token and its withdrawal. Zero disables the holding period. 
+    /// - `early_exit_penalty_bps` - Penalty in BPS of the shares redeemed early. 
+    ///   Zero blocks early withdrawals instead. 
+    ///  
+    /// # Validation 
+    /// - `early_exit_penalty_bps` must be <= `MAX_EARLY_EXIT_PENALTY_BPS` 
+    pub fn set_holding_period<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        min_holding_period_millis: Arg0,
+        early_exit_penalty_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setHoldingPeriod")
+            .argument(&min_holding_period_millis)
+            .argument(&early_exit_penalty_bps)
+            .original_result()
+    }
+
+    /// Returns the holding period as `(min_holding_period_millis, early_exit_penalty_bps)`. 
+    pub fn get_holding_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHoldingPeriod")
+            .original_result()
+    }
+
+    /// Returns the timestamp in milliseconds from which a share token held by an 
+    /// address can be withdrawn without penalty. 
+    ///  
+    /// # Arguments 
+    /// - `address` - Address holding the share token 
+    /// - `share_token_nonce` - Nonce of the share token 
+    ///  
+    /// # Panics 
+    /// Panics if the address does not hold the share token nonce. 
+    pub fn get_holding_period_end<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        address: Arg0,
+        share_token_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHoldingPeriodEnd")
+            .argument(&address)
+            .argument(&share_token_nonce)
+            .original_result()
+    }
+
+    /// Sets the minimum health factor of the lending position. 
+    ///  
+    /// # Arguments 
+    /// - `min_health_factor` - Minimum health factor in WAD precision (e.g. 1.1e18 = 1.1) 
+    ///  
+    /// # Validation 
+    /// - `min_health_factor` must be >= `MIN_HEALTH_FACTOR_FLOOR` 
+    pub fn set_min_health_factor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        min_health_factor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinHealthFactor")
+            .argument(&min_health_factor)
+            .original_result()
+    }
+
+    /// Returns the minimum health factor in WAD precision. 
+    pub fn get_min_health_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinHealthFactor")
+            .original_result()
+    }
+
+    /// Sets the keeper reward. A value of zero disables the reward. 
+    ///  
+    /// # Arguments 
+    /// - `keeper_reward_bps` - Reward in BPS of the amount rebalanced, in EGLD 
+    /// - `max_keeper_reward_in_egld` - Maximum reward per rebalance in EGLD 
+    ///  
+    /// # Validation 
+    /// - `keeper_reward_bps` must be <= `MAX_KEEPER_REWARD_BPS` 
+    pub fn set_keeper_reward<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        keeper_reward_bps: Arg0,
+        max_keeper_reward_in_egld: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setKeeperReward")
+            .argument(&keeper_reward_bps)
+            .argument(&max_keeper_reward_in_egld)
+            .original_result()
+    }
+
+    /// Sets the maximum value lost by a keeper rebalance. 
+    ///  
+    /// # Arguments 
+    /// - `max_keeper_slippage_bps` - Maximum equity lost in BPS of the traded amount 
+    ///  
+    /// # Validation 
+    /// - `max_keeper_slippage_bps` must be <= `MAX_KEEPER_SLIPPAGE_BPS` 
+    pub fn set_max_keeper_slippage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_keeper_slippage_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxKeeperSlippage")
+            .argument(&max_keeper_slippage_bps)
+            .original_result()
+    }
+
+    /// Returns the keeper reward as `(keeper_reward_bps, max_keeper_reward_in_egld)`. 
+    pub fn get_keeper_reward(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getKeeperReward")
+            .original_result()
+    }
+
+    /// Returns the maximum value lost by a keeper rebalance in BPS of the traded amount. 
+    pub fn get_max_keeper_slippage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxKeeperSlippage")
+            .original_result()
+    }
+
+    /// Rebuilds the caller's registry from the share tokens sent with the call. 
+    ///  
+    /// The registered nonces are replaced by the nonces of the payments, and the 
+    /// share tokens are sent back. Sending every held share token registers the 
+    /// ones received through a direct transfer and removes the ones sent away. 
+    ///  
+    /// # Payment 
+    /// Zero or more share tokens (any nonces). 
+    ///  
+    /// # Transfers 
+    /// Sends the share tokens back to the caller. 
+    ///  
+    /// # Panics 
+    /// - Panics if any payment is not a valid share token 
+    pub fn sync_account_positions(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("syncAccountPositions")
+            .original_result()
+    }
 }
 
 #[type_abi]
@@ -603,6 +1797,8 @@ where
     pub swap_router: ManagedAddress<Api>,
     pub bot_address: ManagedAddress<Api>,
     pub admins: ManagedVec<Api, ManagedAddress<Api>>,
+    pub high_water_mark: ManagedDecimal<Api, usize>,
+    pub accrued_fee_shares: ManagedDecimal<Api, usize>,
 }
 
 #[type_abi]
@@ -623,6 +1819,58 @@ where
     pub liquidation_collateral_available_in_egld: ManagedDecimal<Api, usize>,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct PositionPnl<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub nonce: u64,
+    pub share_amount: ManagedDecimal<Api, usize>,
+    pub initial_value_in_egld: ManagedDecimal<Api, usize>,
+    pub current_value_in_egld: ManagedDecimal<Api, usize>,
+    pub pnl_in_egld: multiversx_sc::types::managed::wrapped::managed_decimal::managed_decimal_signed::ManagedDecimalSigned<Api, usize>,
+    pub initial_value_in_usd: ManagedDecimal<Api, usize>,
+    pub current_value_in_usd: ManagedDecimal<Api, usize>,
+    pub pnl_in_usd: multiversx_sc::types::managed::wrapped::managed_decimal::managed_decimal_signed::ManagedDecimalSigned<Api, usize>,
+    pub pnl_percentage: multiversx_sc::types::managed::wrapped::managed_decimal::managed_decimal_signed::ManagedDecimalSigned<Api, usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct AccountPosition<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub attributes: ShareTokenAttributes<Api>,
+    pub pnl: PositionPnl<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug, ManagedVecItem)]
+pub struct ShareTokenAttributes<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub total_share_supply: ManagedDecimal<Api, usize>,
+    pub total_initial_supplied_amount: ManagedDecimal<Api, usize>,
+    pub last_interaction_ts_millis: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct WithdrawAmounts<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub share_amount: ManagedDecimal<Api, usize>,
+    pub redeemed_share_amount: ManagedDecimal<Api, usize>,
+    pub collateral_amount: ManagedDecimal<Api, usize>,
+    pub debt_repaid_in_egld: ManagedDecimal<Api, usize>,
+    pub repayment_collateral_amount: ManagedDecimal<Api, usize>,
+    pub net_amount: ManagedDecimal<Api, usize>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
 pub struct BotInfo<Api>
@@ -648,3 +1896,12 @@ pub enum BotAction {
     Leverage,
     Deleverage,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub enum FeeType {
+    Deposit,
+    Withdraw,
+    Performance,
+    Management,
+}
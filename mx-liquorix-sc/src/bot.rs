@@ -15,11 +15,35 @@
 //! - If ltv_ratio > upper_bound (45%): Deleverage to reduce risk
 //! - If ltv_ratio in [35%, 45%]: No action needed
 //! - If ltv_ratio < lower_bound (35%): Leverage to increase yield
+//!
+//! `leverage` moves the position by one borrowed amount per transaction, while
+//! `leverageToTarget` reaches the target LTV ratio at once through a flash loan.
+//! `flashDeleverage` repays debt through a flash loan instead of swapping collateral
+//! in the lending protocol, and `unwindAll` repays all the debt for incident response.
+//!
+//! `rebalance` lets anyone execute the suggested action when the LTV ratio leaves
+//! the inaction zone, for a keeper reward, so the vault does not depend on the bot alone.
+//!
+//! After execution, every action re-reads the position and requires the equity lost
+//! to swaps to stay below the maximum slippage, since swap prices can differ from the
+//! oracle prices. Every action except `unwindAll` also requires the actual LTV ratio
+//! to be within the zone.
+//!
+//! Leverage actions also require the lending position to stay at or above the
+//! minimum health factor. Below it, deleverage actions, including a deleveraging
+//! `rebalance`, skip the LTV zone bounds.
 
-use crate::constants::{BPS, BPS_PRECISION, WAD_PRECISION};
+use crate::constants::{
+    BPS, BPS_PRECISION, FLASH_LOAN_DELEVERAGE_CALLBACK, FLASH_LOAN_LEVERAGE_CALLBACK, WAD_PRECISION,
+};
 use crate::errors::{
+    ERROR_FLASH_LOAN_NOT_ONGOING, ERROR_FLASH_LOAN_REPAYMENT_NOT_COVERED,
     ERROR_INVALID_COLLATERAL_TO_WITHDRAW, ERROR_INVALID_COLLATERAL_TO_WITHDRAW_WRT_DEBT,
-    ERROR_INVALID_TARGET_LTV_RATIO_BPS,
+    ERROR_INVALID_DEBT_TO_REPAY, ERROR_INVALID_DELEGATED_AMOUNT, ERROR_INVALID_FLASH_LOAN_PAYMENT,
+    ERROR_INVALID_SWAPPED_AMOUNT, ERROR_INVALID_TARGET_LTV_RATIO_BPS,
+    ERROR_LTV_RATIO_NOT_BELOW_TARGET, ERROR_LTV_RATIO_OUT_OF_BOUNDS, ERROR_NO_DEBT_TO_UNWIND,
+    ERROR_NO_REBALANCE_NEEDED, ERROR_REPAID_AMOUNT_BELOW_MIN, ERROR_SLIPPAGE_TOO_HIGH,
+    ERROR_SURPLUS_SWAP_ARGS_REQUIRED, ERROR_SWAP_AMOUNT_BELOW_MIN,
 };
 use crate::structs::{BotAction, BotInfo, BotInfoType};
 use multiversx_sc::imports::*;
@@ -41,6 +65,11 @@ pub trait BotModule:
     + crate::xoxno::liquid_staking::LiquidStakingModule
     // + crate::vault::VaultModule
     + crate::system::attributes::AttributesModule
+    + crate::system::fees::FeesModule
+    + crate::system::positions::PositionsModule
+    + crate::system::caps::CapsModule
+    + crate::system::keeper::KeeperModule
+    + crate::system::health::HealthModule
 {
     // === Endpoints ===
 
@@ -53,17 +82,26 @@ pub trait BotModule:
     /// - `amount_to_borrow` - Amount of the borrowed token to borrow
     /// - `decimals` - Number of decimals for the borrowed token
     /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD)
+    /// - `opt_min_amount_out` - Optional minimum amount of EGLD to receive from the swap
     ///
     /// # Access Control
     /// Only callable by the authorized bot address.
+    ///
+    /// # Panics
+    /// - Panics if the new LTV ratio is outside the suggested zone bounds
+    /// - Panics if the swap returns less than `opt_min_amount_out`
+    /// - Panics if the executed position fails the post-execution checks
+    /// - Panics if the lending position ends below the minimum health factor
     #[endpoint(leverage)]
     fn leverage(
         &self,
         borrowed_amount: BigUint,
         decimals: usize,
         swap_args: ManagedArgBuffer<Self::Api>,
+        opt_min_amount_out: OptionalValue<BigUint>,
     ) {
         self.require_is_bot(&self.caller());
+        self.crystallize_fees();
 
         let lending_address = self.lending_address().get();
         let lending_position_token = self.lending_position_token().get();
@@ -85,9 +123,10 @@ pub trait BotModule:
         );
         let borrow_amount_in_egld = borrow_amount_md.clone().mul(borrowed_token_price_in_egld);
 
-        let new_total_collateral_in_egld =
-            total_collateral_in_egld.add(borrow_amount_in_egld.clone());
-        let new_total_debt_in_egld = total_debt_in_egld.add(borrow_amount_in_egld);
+        let new_total_collateral_in_egld = total_collateral_in_egld
+            .clone()
+            .add(borrow_amount_in_egld.clone());
+        let new_total_debt_in_egld = total_debt_in_egld.clone().add(borrow_amount_in_egld.clone());
 
         let new_ltv_ratio_bps =
             self.calculate_ltv_ratio_bps(new_total_collateral_in_egld, new_total_debt_in_egld);
@@ -116,6 +155,12 @@ pub trait BotModule:
             &self.caller(),
             swap_args,
         );
+        if let OptionalValue::Some(min_amount_out) = opt_min_amount_out {
+            require!(
+                egld_payment.amount >= min_amount_out,
+                ERROR_SWAP_AMOUNT_BELOW_MIN
+            );
+        }
 
         let xegld_payment =
             self.execute_delegation(&self.liquid_staking_address().get(), &egld_payment.amount);
@@ -130,6 +175,184 @@ pub trait BotModule:
         );
 
         self.has_debt().set_if_empty(true);
+
+        self.require_valid_execution(
+            &lending_address,
+            lending_position_nonce,
+            total_collateral_in_egld,
+            total_debt_in_egld,
+            borrow_amount_in_egld,
+            self.max_slippage_bps().get(),
+            true,
+        );
+        self.require_min_health_factor(&lending_address, lending_position_nonce);
+    }
+
+    /// Increases the leverage of the vault position up to a target LTV ratio in a single transaction.
+    ///
+    /// Flash-borrows the full adjustment amount reported by `getBotInfo` for the target,
+    /// which `flashLoanLeverageCallback` swaps to EGLD, delegates to xEGLD and supplies,
+    /// before borrowing against the new collateral to repay the flash loan and its fee.
+    ///
+    /// # Arguments
+    /// - `target_ltv_ratio_bps` - Target LTV ratio in BPS
+    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD)
+    ///
+    /// # Access Control
+    /// Only callable by the authorized bot address.
+    ///
+    /// # Panics
+    /// - Panics if the target LTV ratio is outside the suggested zone bounds
+    /// - Panics if the current LTV ratio is already at or above the target
+    /// - Panics if the executed position fails the post-execution checks
+    /// - Panics if the lending position ends below the minimum health factor
+    #[endpoint(leverageToTarget)]
+    fn leverage_to_target(
+        &self,
+        target_ltv_ratio_bps: BigUint,
+        swap_args: ManagedArgBuffer<Self::Api>,
+    ) {
+        self.require_is_bot(&self.caller());
+
+        let target_ltv_ratio_bps_md = self.biguint_to_md(target_ltv_ratio_bps, BPS_PRECISION);
+        self.require_target_ltv_ratio_within_suggested_bounds(
+            &target_ltv_ratio_bps_md,
+            &self.biguint_to_md(
+                BigUint::from(self.safe_threshold_bps().get()),
+                BPS_PRECISION,
+            ),
+            &self.biguint_to_md(BigUint::from(self.inaction_zone_bps().get()), BPS_PRECISION),
+        );
+
+        self.crystallize_fees();
+
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let borrowed_token = self.borrowed_token().get();
+
+        let total_collateral_in_egld =
+            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+        let total_debt_in_egld =
+            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+
+        let current_ltv_ratio_bps = self
+            .calculate_ltv_ratio_bps(total_collateral_in_egld.clone(), total_debt_in_egld.clone());
+        require!(
+            current_ltv_ratio_bps.lt(&target_ltv_ratio_bps_md),
+            ERROR_LTV_RATIO_NOT_BELOW_TARGET
+        );
+
+        let adjustment_amount_in_egld = self.calculate_adjustment_amount_in_egld(
+            total_collateral_in_egld.clone(),
+            total_debt_in_egld.clone(),
+            target_ltv_ratio_bps_md.clone(),
+        );
+
+        let borrowed_token_price_in_egld = self.get_token_price_in_egld(
+            &lending_address,
+            &EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone()),
+        );
+        let flash_loan_amount = self.execute_flash_loan_leverage(
+            &lending_address,
+            &borrowed_token,
+            &adjustment_amount_in_egld,
+            borrowed_token_price_in_egld,
+            swap_args,
+        );
+
+        self.require_valid_execution(
+            &lending_address,
+            lending_position_nonce,
+            total_collateral_in_egld,
+            total_debt_in_egld,
+            adjustment_amount_in_egld.clone(),
+            self.max_slippage_bps().get(),
+            true,
+        );
+        self.require_min_health_factor(&lending_address, lending_position_nonce);
+
+        self.event_leverage_to_target(
+            &target_ltv_ratio_bps_md,
+            &flash_loan_amount,
+            &adjustment_amount_in_egld,
+        );
+    }
+
+    /// Receives the flash loan taken by `leverageToTarget` and leverages the vault position with it.
+    ///
+    /// Swaps the loan to EGLD, delegates it to xEGLD and supplies it, then borrows
+    /// the loan plus the flash loan fee against the new collateral and sends it back.
+    ///
+    /// # Arguments
+    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD)
+    /// - `decimals` - Number of decimals for the borrowed token
+    /// - `refunds_receiver` - Address receiving any swap refunds
+    /// - `initiator` - Address that took the flash loan, appended by the lending protocol
+    ///
+    /// # Payment
+    /// The flash-borrowed tokens.
+    ///
+    /// # Transfers
+    /// Sends the loan plus the flash loan fee back to the caller.
+    ///
+    /// # Access Control
+    /// Only callable by the lending controller during a flash loan taken by the vault.
+    #[payable]
+    #[endpoint(flashLoanLeverageCallback)]
+    fn flash_loan_leverage_callback(
+        &self,
+        swap_args: ManagedArgBuffer<Self::Api>,
+        decimals: usize,
+        refunds_receiver: ManagedAddress,
+        initiator: ManagedAddress,
+    ) {
+        let loan_payment = self.require_flash_loan_payment(&initiator);
+        let borrowed_token = loan_payment.token_identifier.clone();
+
+        let lending_address = self.lending_address().get();
+        let lending_position_token = self.lending_position_token().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let supplied_token = self.supplied_token().get();
+
+        let egld_payment = self.execute_swap(
+            &self.swap_router_address().get(),
+            &borrowed_token,
+            &loan_payment.amount,
+            &EgldOrEsdtTokenIdentifier::egld(),
+            &refunds_receiver,
+            swap_args,
+        );
+
+        let xegld_payment =
+            self.execute_delegation(&self.liquid_staking_address().get(), &egld_payment.amount);
+
+        let supply_amount_md = self.biguint_to_md(xegld_payment.amount, WAD_PRECISION);
+        self.execute_supply(
+            &lending_address,
+            supplied_token,
+            &supply_amount_md,
+            lending_position_token.clone(),
+            lending_position_nonce,
+        );
+
+        let loan_amount_md = self.biguint_to_md(loan_payment.amount, decimals);
+        let repayment_amount_md =
+            self.calculate_flash_loan_repayment(&lending_address, &borrowed_token, loan_amount_md);
+
+        self.execute_borrow(
+            &lending_address,
+            borrowed_token.clone(),
+            &repayment_amount_md,
+            lending_position_token,
+            lending_position_nonce,
+        );
+
+        self.has_debt().set_if_empty(true);
+
+        self.tx()
+            .to(&self.caller())
+            .single_esdt(&borrowed_token, 0, &self.md_to_biguint(&repayment_amount_md))
+            .transfer();
     }
 
     /// Decreases the leverage of the vault position.
@@ -138,15 +361,31 @@ pub trait BotModule:
     /// Uses the lending protocol's built-in swap functionality to convert collateral
     /// to borrowed token and repay debt in a single transaction.
     ///
+    /// While the lending position is below the minimum health factor, the new LTV
+    /// ratio is not required to be within the suggested zone bounds.
+    ///
     /// # Arguments
     /// - `collateral_amount` - Amount of the collateral token to use for repayment
     /// - `swap_steps` - Steps for the internal swap operation (e.g. xEGLD -> USDC)
+    /// - `opt_min_repaid_amount` - Optional minimum amount of the borrowed token to repay
     ///
     /// # Access Control
     /// Only callable by the authorized bot address.
+    ///
+    /// # Panics
+    /// - Panics if the new LTV ratio is outside the suggested zone bounds, unless the
+    ///   position is below the minimum health factor
+    /// - Panics if the repaid debt is below `opt_min_repaid_amount`
+    /// - Panics if the executed position fails the post-execution checks
     #[endpoint(deleverage)]
-    fn deleverage(&self, collateral_amount: BigUint, swap_steps: ManagedArgBuffer<Self::Api>) {
+    fn deleverage(
+        &self,
+        collateral_amount: BigUint,
+        swap_steps: ManagedArgBuffer<Self::Api>,
+        opt_min_repaid_amount: OptionalValue<BigUint>,
+    ) {
         self.require_is_bot(&self.caller());
+        self.crystallize_fees();
 
         let lending_address = self.lending_address().get();
         let lending_position_token = self.lending_position_token().get();
@@ -180,20 +419,34 @@ pub trait BotModule:
             ERROR_INVALID_COLLATERAL_TO_WITHDRAW_WRT_DEBT
         );
 
-        let new_total_collateral_in_egld =
-            total_collateral_in_egld.sub(collateral_to_withdraw_in_egld.clone());
+        let new_total_collateral_in_egld = total_collateral_in_egld
+            .clone()
+            .sub(collateral_to_withdraw_in_egld.clone());
         let new_total_debt_in_egld = total_debt_in_egld
             .clone()
             .sub(collateral_to_withdraw_in_egld.clone());
-        let new_ltv_ratio_bps = self
-            .calculate_ltv_ratio_bps(new_total_collateral_in_egld, new_total_debt_in_egld.clone());
-        self.require_target_ltv_ratio_within_suggested_bounds(
-            &new_ltv_ratio_bps,
-            &self.biguint_to_md(
-                BigUint::from(self.safe_threshold_bps().get()),
-                BPS_PRECISION,
-            ),
-            &self.biguint_to_md(BigUint::from(self.inaction_zone_bps().get()), BPS_PRECISION),
+        let enforce_ltv_bounds =
+            !self.is_below_min_health_factor(&lending_address, lending_position_nonce);
+        if enforce_ltv_bounds {
+            let new_ltv_ratio_bps = self.calculate_ltv_ratio_bps(
+                new_total_collateral_in_egld,
+                new_total_debt_in_egld.clone(),
+            );
+            self.require_target_ltv_ratio_within_suggested_bounds(
+                &new_ltv_ratio_bps,
+                &self.biguint_to_md(
+                    BigUint::from(self.safe_threshold_bps().get()),
+                    BPS_PRECISION,
+                ),
+                &self.biguint_to_md(BigUint::from(self.inaction_zone_bps().get()), BPS_PRECISION),
+            );
+        }
+
+        let total_debt_before = self.get_total_borrowed(
+            &lending_address,
+            lending_position_nonce,
+            &borrowed_token,
+            self.has_debt().get(),
         );
 
         self.execute_repay_with_collateral(
@@ -202,21 +455,37 @@ pub trait BotModule:
             lending_position_nonce,
             supplied_token,
             &collateral_amount_md,
-            borrowed_token,
+            borrowed_token.clone(),
             swap_steps,
         );
 
-        let remaining_collateral =
-            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
-        if !self.is_md_gt_zero(&remaining_collateral) {
-            self.has_collateral().set(false);
-        }
-        let remaining_debt =
-            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
-        if !self.is_md_gt_zero(&remaining_debt) {
-            self.has_debt().set(false);
+        self.refresh_position_flags(&lending_address, lending_position_nonce);
+
+        if let OptionalValue::Some(min_repaid_amount) = opt_min_repaid_amount {
+            let total_debt_after = self.get_total_borrowed(
+                &lending_address,
+                lending_position_nonce,
+                &borrowed_token,
+                self.has_debt().get(),
+            );
+            let repaid_amount = self.md_to_biguint(&total_debt_before)
+                - self.md_to_biguint(&total_debt_after);
+            require!(
+                repaid_amount >= min_repaid_amount,
+                ERROR_REPAID_AMOUNT_BELOW_MIN
+            );
         }
 
+        self.require_valid_execution(
+            &lending_address,
+            lending_position_nonce,
+            total_collateral_in_egld,
+            total_debt_in_egld,
+            collateral_to_withdraw_in_egld.clone(),
+            self.max_slippage_bps().get(),
+            enforce_ltv_bounds,
+        );
+
         self.event_deleverage(
             &collateral_amount_md,
             &collateral_to_withdraw_in_egld,
@@ -224,6 +493,440 @@ pub trait BotModule:
         );
     }
 
+    /// Decreases the leverage of the vault position through a flash loan.
+    ///
+    /// Flash-borrows the debt token to repay debt, which `flashLoanDeleverageCallback`
+    /// follows by withdrawing the freed collateral and swapping it back to repay the
+    /// flash loan and its fee. An alternative to `deleverage` when repaying debt with
+    /// collateral through the lending protocol is constrained.
+    ///
+    /// While the lending position is below the minimum health factor, the new LTV
+    /// ratio is not required to be within the suggested zone bounds.
+    ///
+    /// # Arguments
+    /// - `debt_amount` - Amount of the borrowed token to repay
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC)
+    /// - `opt_surplus_swap_args` - Swap route of the surplus to EGLD (e.g. USDC -> EGLD),
+    ///   required when the whole debt is repaid and a surplus is left
+    ///
+    /// # Access Control
+    /// Only callable by the authorized bot address.
+    ///
+    /// # Panics
+    /// - Panics if the debt or collateral amount exceeds the position
+    /// - Panics if the new LTV ratio is outside the suggested zone bounds, unless the
+    ///   position is below the minimum health factor
+    /// - Panics if the executed position fails the post-execution checks
+    #[endpoint(flashDeleverage)]
+    fn flash_deleverage(
+        &self,
+        debt_amount: BigUint,
+        collateral_amount: BigUint,
+        swap_args: ManagedArgBuffer<Self::Api>,
+        opt_surplus_swap_args: OptionalValue<ManagedArgBuffer<Self::Api>>,
+    ) {
+        self.require_is_bot(&self.caller());
+        self.crystallize_fees();
+
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let borrowed_token = self.borrowed_token().get();
+        let borrowed_token_id = EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone());
+
+        let debt_amount_md = self.biguint_to_md(
+            debt_amount,
+            self.get_token_decimals(&lending_address, &borrowed_token_id),
+        );
+        let total_debt = self.get_total_borrowed(
+            &lending_address,
+            lending_position_nonce,
+            &borrowed_token,
+            self.has_debt().get(),
+        );
+        require!(debt_amount_md.le(&total_debt), ERROR_INVALID_DEBT_TO_REPAY);
+
+        let collateral_amount_md = self.biguint_to_md(collateral_amount.clone(), WAD_PRECISION);
+        let total_collateral = self.get_total_supplied(
+            &lending_address,
+            lending_position_nonce,
+            &self.supplied_token().get(),
+            self.has_collateral().get(),
+        );
+        require!(
+            collateral_amount_md.le(&total_collateral),
+            ERROR_INVALID_COLLATERAL_TO_WITHDRAW
+        );
+
+        let total_collateral_in_egld =
+            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+        let collateral_to_withdraw_in_egld = collateral_amount_md
+            .mul(total_collateral_in_egld.clone())
+            .div(total_collateral);
+        let total_debt_in_egld =
+            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+        let debt_to_repay_in_egld = debt_amount_md
+            .clone()
+            .mul(self.get_token_price_in_egld(&lending_address, &borrowed_token_id));
+
+        let new_total_collateral_in_egld = total_collateral_in_egld
+            .clone()
+            .sub(collateral_to_withdraw_in_egld.clone());
+        let new_total_debt_in_egld = total_debt_in_egld.clone().sub(debt_to_repay_in_egld);
+        let enforce_ltv_bounds =
+            !self.is_below_min_health_factor(&lending_address, lending_position_nonce);
+        if enforce_ltv_bounds {
+            let new_ltv_ratio_bps = self.calculate_ltv_ratio_bps(
+                new_total_collateral_in_egld,
+                new_total_debt_in_egld.clone(),
+            );
+            self.require_target_ltv_ratio_within_suggested_bounds(
+                &new_ltv_ratio_bps,
+                &self.biguint_to_md(
+                    BigUint::from(self.safe_threshold_bps().get()),
+                    BPS_PRECISION,
+                ),
+                &self.biguint_to_md(BigUint::from(self.inaction_zone_bps().get()), BPS_PRECISION),
+            );
+        }
+
+        self.execute_flash_loan_deleverage(
+            &lending_address,
+            &borrowed_token,
+            &debt_amount_md,
+            collateral_amount.clone(),
+            swap_args,
+            opt_surplus_swap_args
+                .into_option()
+                .unwrap_or_else(ManagedArgBuffer::new),
+        );
+        self.refresh_position_flags(&lending_address, lending_position_nonce);
+
+        self.require_valid_execution(
+            &lending_address,
+            lending_position_nonce,
+            total_collateral_in_egld,
+            total_debt_in_egld,
+            collateral_to_withdraw_in_egld,
+            self.max_slippage_bps().get(),
+            enforce_ltv_bounds,
+        );
+
+        self.event_flash_deleverage(&debt_amount_md, &collateral_amount, &new_total_debt_in_egld);
+    }
+
+    /// Repays all the debt of the vault position in a single transaction, for incident response.
+    ///
+    /// Flash-borrows the full debt and unwinds it as `flashDeleverage` does, without
+    /// checking the resulting LTV ratio against the suggested zone bounds. The equity
+    /// lost is still checked against the maximum slippage.
+    ///
+    /// # Arguments
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap,
+    ///   which must cover the debt and the flash loan fee
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC)
+    /// - `opt_surplus_swap_args` - Swap route of the surplus to EGLD (e.g. USDC -> EGLD),
+    ///   required when a surplus is left
+    ///
+    /// # Access Control
+    /// Only callable by an admin.
+    ///
+    /// # Panics
+    /// - Panics if the vault has no debt
+    /// - Panics if the collateral amount exceeds the position
+    /// - Panics if the equity lost exceeds the maximum slippage
+    #[endpoint(unwindAll)]
+    fn unwind_all(
+        &self,
+        collateral_amount: BigUint,
+        swap_args: ManagedArgBuffer<Self::Api>,
+        opt_surplus_swap_args: OptionalValue<ManagedArgBuffer<Self::Api>>,
+    ) {
+        self.require_is_admin(&self.caller());
+        self.crystallize_fees();
+
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let borrowed_token = self.borrowed_token().get();
+
+        let total_debt = self.get_total_borrowed(
+            &lending_address,
+            lending_position_nonce,
+            &borrowed_token,
+            self.has_debt().get(),
+        );
+        require!(self.is_md_gt_zero(&total_debt), ERROR_NO_DEBT_TO_UNWIND);
+
+        let total_collateral = self.get_total_supplied(
+            &lending_address,
+            lending_position_nonce,
+            &self.supplied_token().get(),
+            self.has_collateral().get(),
+        );
+        let collateral_amount_md = self.biguint_to_md(collateral_amount.clone(), WAD_PRECISION);
+        require!(
+            collateral_amount_md.le(&total_collateral),
+            ERROR_INVALID_COLLATERAL_TO_WITHDRAW
+        );
+
+        let total_collateral_in_egld =
+            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+        let total_debt_in_egld =
+            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+        let collateral_to_withdraw_in_egld = collateral_amount_md
+            .mul(total_collateral_in_egld.clone())
+            .div(total_collateral);
+
+        self.execute_flash_loan_deleverage(
+            &lending_address,
+            &borrowed_token,
+            &total_debt,
+            collateral_amount.clone(),
+            swap_args,
+            opt_surplus_swap_args
+                .into_option()
+                .unwrap_or_else(ManagedArgBuffer::new),
+        );
+        self.refresh_position_flags(&lending_address, lending_position_nonce);
+
+        self.require_valid_execution(
+            &lending_address,
+            lending_position_nonce,
+            total_collateral_in_egld,
+            total_debt_in_egld,
+            collateral_to_withdraw_in_egld,
+            self.max_slippage_bps().get(),
+            false,
+        );
+
+        self.event_unwind_all(&total_debt, &collateral_amount);
+    }
+
+    /// Receives the flash loan taken by `flashDeleverage` or `unwindAll` and deleverages
+    /// the vault position with it.
+    ///
+    /// Repays debt with the loan, withdraws the freed collateral and swaps it to the
+    /// borrowed token, then sends back the loan plus the flash loan fee. Any swapped
+    /// amount left repays more debt. Once the debt is fully repaid, it is swapped to
+    /// EGLD, delegated and supplied as xEGLD, so that it stays in the vault equity and
+    /// is paid out pro-rata by the withdrawals.
+    ///
+    /// # Arguments
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC)
+    /// - `surplus_swap_args` - Swap route of the surplus to EGLD (e.g. USDC -> EGLD),
+    ///   empty when not provided
+    /// - `decimals` - Number of decimals for the borrowed token
+    /// - `refunds_receiver` - Address receiving any swap refunds
+    /// - `initiator` - Address that took the flash loan, appended by the lending protocol
+    ///
+    /// # Payment
+    /// The flash-borrowed tokens.
+    ///
+    /// # Transfers
+    /// Sends the loan plus the flash loan fee back to the caller.
+    ///
+    /// # Access Control
+    /// Only callable by the lending controller during a flash loan taken by the vault.
+    ///
+    /// # Panics
+    /// - Panics if the swapped amount does not cover the loan plus the flash loan fee
+    /// - Panics if a surplus is left once the debt is fully repaid and no surplus route is given
+    #[payable]
+    #[endpoint(flashLoanDeleverageCallback)]
+    fn flash_loan_deleverage_callback(
+        &self,
+        collateral_amount: BigUint,
+        swap_args: ManagedArgBuffer<Self::Api>,
+        surplus_swap_args: ManagedArgBuffer<Self::Api>,
+        decimals: usize,
+        refunds_receiver: ManagedAddress,
+        initiator: ManagedAddress,
+    ) {
+        let loan_payment = self.require_flash_loan_payment(&initiator);
+        let borrowed_token = loan_payment.token_identifier.clone();
+
+        let lending_address = self.lending_address().get();
+        let lending_position_token = self.lending_position_token().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let supplied_token = self.supplied_token().get();
+
+        let loan_amount_md = self.biguint_to_md(loan_payment.amount, decimals);
+        self.execute_repay(
+            &lending_address,
+            &borrowed_token,
+            lending_position_nonce,
+            &loan_amount_md,
+        );
+
+        self.execute_withdraw(
+            &lending_address,
+            &supplied_token,
+            &self.biguint_to_md(collateral_amount.clone(), WAD_PRECISION),
+            lending_position_token.clone(),
+            lending_position_nonce,
+        );
+
+        let swapped_payment = self.execute_swap(
+            &self.swap_router_address().get(),
+            &supplied_token,
+            &collateral_amount,
+            &EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone()),
+            &refunds_receiver,
+            swap_args,
+        );
+
+        let repayment_amount = self.md_to_biguint(&self.calculate_flash_loan_repayment(
+            &lending_address,
+            &borrowed_token,
+            loan_amount_md,
+        ));
+        require!(
+            swapped_payment.amount >= repayment_amount,
+            ERROR_FLASH_LOAN_REPAYMENT_NOT_COVERED
+        );
+
+        let surplus_amount = &swapped_payment.amount - &repayment_amount;
+        if self.is_biguint_gt_zero(&surplus_amount) {
+            let remaining_debt =
+                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+            if self.is_md_gt_zero(&remaining_debt) {
+                self.execute_repay(
+                    &lending_address,
+                    &borrowed_token,
+                    lending_position_nonce,
+                    &self.biguint_to_md(surplus_amount, decimals),
+                );
+            } else {
+                require!(!surplus_swap_args.is_empty(), ERROR_SURPLUS_SWAP_ARGS_REQUIRED);
+
+                let egld_payment = self.execute_swap(
+                    &self.swap_router_address().get(),
+                    &borrowed_token,
+                    &surplus_amount,
+                    &EgldOrEsdtTokenIdentifier::egld(),
+                    &refunds_receiver,
+                    surplus_swap_args,
+                );
+                require!(
+                    self.is_biguint_gt_zero(&egld_payment.amount),
+                    ERROR_INVALID_SWAPPED_AMOUNT
+                );
+
+                let delegation_payment = self.execute_delegation(
+                    &self.liquid_staking_address().get(),
+                    &egld_payment.amount,
+                );
+                require!(
+                    self.is_biguint_gt_zero(&delegation_payment.amount),
+                    ERROR_INVALID_DELEGATED_AMOUNT
+                );
+
+                self.execute_supply(
+                    &lending_address,
+                    supplied_token,
+                    &self.biguint_to_md(delegation_payment.amount, WAD_PRECISION),
+                    lending_position_token,
+                    lending_position_nonce,
+                );
+            }
+        }
+
+        self.tx()
+            .to(&self.caller())
+            .single_esdt(&borrowed_token, 0, &repayment_amount)
+            .transfer();
+    }
+
+    /// Rebalances the vault position to the safe threshold, callable by anyone.
+    ///
+    /// Only allowed when `getBotInfo` suggests to leverage or deleverage. Executes
+    /// exactly the suggested adjustment, like `leverageToTarget` when leveraging, and
+    /// by repaying debt with collateral through the lending protocol when deleveraging.
+    /// The caller is paid the keeper reward for the adjustment, as a share token.
+    ///
+    /// While the lending position is below the minimum health factor, a deleverage
+    /// skips the LTV zone bounds after execution, as in `deleverage`.
+    ///
+    /// The caller chooses the swap route, so the equity lost is bounded by the tighter
+    /// `max_keeper_slippage_bps` instead of the `max_slippage_bps` of the bot actions.
+    ///
+    /// # Arguments
+    /// - `swap_args` - Arguments for the swap operation: swap route for the flash loan
+    ///   when leveraging (e.g. USDC -> EGLD), swap steps for the lending protocol when
+    ///   deleveraging (e.g. xEGLD -> USDC)
+    ///
+    /// # Panics
+    /// - Panics if the contract is paused
+    /// - Panics if the LTV ratio is within the inaction zone
+    /// - Panics if the executed position fails the post-execution checks
+    /// - Panics if leveraging leaves the lending position below the minimum health factor
+    #[endpoint(rebalance)]
+    fn rebalance(&self, swap_args: ManagedArgBuffer<Self::Api>) {
+        self.require_not_paused();
+        self.crystallize_fees();
+
+        let bot_info = self.get_bot_info(OptionalValue::None);
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+
+        let enforce_ltv_bounds = match bot_info.suggested_action {
+            BotAction::Leverage => {
+                self.execute_flash_loan_leverage(
+                    &lending_address,
+                    &self.borrowed_token().get(),
+                    &bot_info.adjustment_amount_in_egld,
+                    bot_info.borrowed_token_price_in_egld,
+                    swap_args,
+                );
+                self.require_min_health_factor(&lending_address, lending_position_nonce);
+                true
+            },
+            BotAction::Deleverage => {
+                let enforce_ltv_bounds =
+                    !self.is_below_min_health_factor(&lending_address, lending_position_nonce);
+                let collateral_amount_md = bot_info
+                    .adjustment_amount_in_egld
+                    .clone()
+                    .mul(self.wad_as_md())
+                    .div(bot_info.supplied_token_price_in_egld);
+                self.execute_repay_with_collateral(
+                    &lending_address,
+                    self.lending_position_token().get(),
+                    lending_position_nonce,
+                    self.supplied_token().get(),
+                    &collateral_amount_md,
+                    self.borrowed_token().get(),
+                    swap_args,
+                );
+                self.refresh_position_flags(&lending_address, lending_position_nonce);
+                enforce_ltv_bounds
+            },
+            BotAction::Inaction => sc_panic!(ERROR_NO_REBALANCE_NEEDED),
+        };
+
+        self.require_valid_execution(
+            &lending_address,
+            lending_position_nonce,
+            bot_info.total_collateral_in_egld.clone(),
+            bot_info.total_debt_in_egld.clone(),
+            bot_info.adjustment_amount_in_egld.clone(),
+            self.max_keeper_slippage_bps().get(),
+            enforce_ltv_bounds,
+        );
+
+        let keeper = self.caller();
+        let keeper_reward_in_egld =
+            self.calculate_keeper_reward_in_egld(bot_info.adjustment_amount_in_egld.clone());
+        self.pay_keeper_reward(&keeper, &keeper_reward_in_egld);
+
+        self.event_rebalance(
+            &keeper,
+            bot_info.suggested_action,
+            &bot_info.adjustment_amount_in_egld,
+        );
+    }
+
     // === Views ===
 
     /// Returns information for the bot to decide whether to leverage, deleverage, or do nothing.
@@ -446,6 +1149,286 @@ pub trait BotModule:
         );
     }
 
+    /// Takes a flash loan of the borrowed token, received by one of the vault's callback endpoints.
+    ///
+    /// Marks the flash loan as ongoing for the duration of the call, so that
+    /// the callback endpoints only accept loans taken by the vault.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `borrowed_token` - Token to flash-borrow
+    /// - `amount` - Amount to flash-borrow
+    /// - `endpoint` - Callback endpoint receiving the loan
+    /// - `arguments` - Arguments passed to the callback endpoint
+    fn take_flash_loan(
+        &self,
+        lending_address: &ManagedAddress,
+        borrowed_token: &EsdtTokenIdentifier,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        endpoint: &[u8],
+        arguments: ManagedArgBuffer<Self::Api>,
+    ) {
+        self.flash_loan_ongoing().set(true);
+        self.execute_flash_loan(
+            lending_address,
+            borrowed_token,
+            amount,
+            ManagedBuffer::from(endpoint),
+            arguments,
+        );
+        self.flash_loan_ongoing().clear();
+    }
+
+    /// Takes a flash loan of the adjustment amount leveraging the position in `flashLoanLeverageCallback`.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `borrowed_token` - Token to flash-borrow
+    /// - `adjustment_amount_in_egld` - Amount to leverage by, in EGLD
+    /// - `borrowed_token_price_in_egld` - Price of the borrowed token in EGLD
+    /// - `swap_args` - Arguments for the swap operation (e.g. USDC -> EGLD)
+    ///
+    /// # Returns
+    /// The flash-borrowed amount.
+    fn execute_flash_loan_leverage(
+        &self,
+        lending_address: &ManagedAddress,
+        borrowed_token: &EsdtTokenIdentifier,
+        adjustment_amount_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        borrowed_token_price_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+        swap_args: ManagedArgBuffer<Self::Api>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let borrowed_token_decimals = self.get_token_decimals(
+            lending_address,
+            &EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone()),
+        );
+        let flash_loan_amount = adjustment_amount_in_egld
+            .clone()
+            .mul(self.wad_as_md())
+            .div(borrowed_token_price_in_egld)
+            .rescale(borrowed_token_decimals);
+
+        let mut arguments = ManagedArgBuffer::new();
+        arguments.push_arg(&swap_args);
+        arguments.push_arg(borrowed_token_decimals);
+        arguments.push_arg(self.caller());
+
+        self.take_flash_loan(
+            lending_address,
+            borrowed_token,
+            &flash_loan_amount,
+            FLASH_LOAN_LEVERAGE_CALLBACK,
+            arguments,
+        );
+
+        flash_loan_amount
+    }
+
+    /// Takes a flash loan of `debt_amount` deleveraging the position in `flashLoanDeleverageCallback`.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `borrowed_token` - Token to flash-borrow and repay
+    /// - `debt_amount` - Amount of debt to repay
+    /// - `collateral_amount` - Amount of the collateral token to withdraw and swap
+    /// - `swap_args` - Arguments for the swap operation (e.g. xEGLD -> USDC)
+    /// - `surplus_swap_args` - Swap route of the surplus to EGLD, possibly empty
+    fn execute_flash_loan_deleverage(
+        &self,
+        lending_address: &ManagedAddress,
+        borrowed_token: &EsdtTokenIdentifier,
+        debt_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        collateral_amount: BigUint,
+        swap_args: ManagedArgBuffer<Self::Api>,
+        surplus_swap_args: ManagedArgBuffer<Self::Api>,
+    ) {
+        let mut arguments = ManagedArgBuffer::new();
+        arguments.push_arg(collateral_amount);
+        arguments.push_arg(&swap_args);
+        arguments.push_arg(&surplus_swap_args);
+        arguments.push_arg(debt_amount.scale());
+        arguments.push_arg(self.caller());
+
+        self.take_flash_loan(
+            lending_address,
+            borrowed_token,
+            debt_amount,
+            FLASH_LOAN_DELEVERAGE_CALLBACK,
+            arguments,
+        );
+    }
+
+    /// Requires a callback to be part of a flash loan taken by the vault and returns the loan.
+    ///
+    /// # Arguments
+    /// - `initiator` - Address that took the flash loan, appended by the lending protocol
+    ///
+    /// # Returns
+    /// The flash-borrowed payment.
+    ///
+    /// # Panics
+    /// - Panics if no vault flash loan is ongoing, the vault did not take it, or the
+    ///   caller is not the lending controller
+    /// - Panics if the payment is not a single borrowed token payment
+    fn require_flash_loan_payment(&self, initiator: &ManagedAddress) -> EsdtTokenPayment {
+        require!(
+            self.flash_loan_ongoing().get()
+                && initiator == &self.sc()
+                && self.caller() == self.lending_address().get(),
+            ERROR_FLASH_LOAN_NOT_ONGOING
+        );
+
+        let loan_payment = self.call_value().single_esdt().clone();
+        require!(
+            loan_payment.token_identifier == self.borrowed_token().get()
+                && loan_payment.token_nonce == 0,
+            ERROR_INVALID_FLASH_LOAN_PAYMENT
+        );
+
+        loan_payment
+    }
+
+    /// Calculates the amount to send back for a flash loan, including the flash loan fee.
+    ///
+    /// The fee is rounded up, so the repayment always covers it.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `borrowed_token` - Flash-borrowed token
+    /// - `loan_amount` - Flash-borrowed amount
+    ///
+    /// # Returns
+    /// The loan plus the flash loan fee as ManagedDecimal.
+    fn calculate_flash_loan_repayment(
+        &self,
+        lending_address: &ManagedAddress,
+        borrowed_token: &EsdtTokenIdentifier,
+        loan_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let flash_loan_fee_bps = self
+            .get_flash_loan_fee_bps(
+                lending_address,
+                &EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone()),
+            )
+            .rescale(BPS_PRECISION);
+        let flash_loan_fee = self.mul_div_ceil_md(
+            &loan_amount,
+            &flash_loan_fee_bps,
+            &self.biguint_to_md(BigUint::from(BPS), BPS_PRECISION),
+        );
+
+        loan_amount.add(flash_loan_fee)
+    }
+
+    /// Checks the lending position after a bot action against the one before it.
+    ///
+    /// Swaps are executed at market prices, so the projected LTV ratio checked
+    /// before execution can differ from the actual one. This re-reads the position and requires:
+    /// - the actual LTV ratio to be within the suggested zone bounds, if `enforce_ltv_bounds`
+    /// - the equity lost to be <= `traded_amount_in_egld * max_slippage_bps / BPS`
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `lending_position_nonce` - Nonce of the position NFT
+    /// - `total_collateral_in_egld_before` - Total collateral value before the action (in EGLD)
+    /// - `total_debt_in_egld_before` - Total borrowed value before the action (in EGLD)
+    /// - `traded_amount_in_egld` - Value swapped by the action (in EGLD)
+    /// - `max_slippage_bps` - Maximum equity lost in BPS of the traded amount
+    /// - `enforce_ltv_bounds` - Whether to check the LTV ratio against the zone bounds
+    ///
+    /// # Panics
+    /// - Panics if the LTV ratio after execution is outside the suggested zone bounds
+    /// - Panics if the equity lost exceeds the maximum slippage
+    fn require_valid_execution(
+        &self,
+        lending_address: &ManagedAddress,
+        lending_position_nonce: u64,
+        total_collateral_in_egld_before: ManagedDecimal<Self::Api, NumDecimals>,
+        total_debt_in_egld_before: ManagedDecimal<Self::Api, NumDecimals>,
+        traded_amount_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+        max_slippage_bps: u64,
+        enforce_ltv_bounds: bool,
+    ) {
+        let total_collateral_in_egld_after =
+            self.get_total_supplied_in_egld(lending_address, lending_position_nonce);
+        let total_debt_in_egld_after =
+            self.get_total_borrowed_in_egld(lending_address, lending_position_nonce);
+
+        let ltv_ratio_bps_before = self.calculate_ltv_ratio_bps(
+            total_collateral_in_egld_before.clone(),
+            total_debt_in_egld_before.clone(),
+        );
+        let ltv_ratio_bps_after = self.calculate_ltv_ratio_bps(
+            total_collateral_in_egld_after.clone(),
+            total_debt_in_egld_after.clone(),
+        );
+        if enforce_ltv_bounds {
+            let (lower_bound_bps_md, upper_bound_bps_md) = self.get_ltv_ratio_zone_bounds_md(
+                self.biguint_to_md(
+                    BigUint::from(self.safe_threshold_bps().get()),
+                    BPS_PRECISION,
+                ),
+                self.biguint_to_md(BigUint::from(self.inaction_zone_bps().get()), BPS_PRECISION),
+            );
+            require!(
+                ltv_ratio_bps_after.ge(&lower_bound_bps_md)
+                    && ltv_ratio_bps_after.le(&upper_bound_bps_md),
+                ERROR_LTV_RATIO_OUT_OF_BOUNDS
+            );
+        }
+
+        let equity_before_in_egld =
+            self.saturating_sub_md(total_collateral_in_egld_before, total_debt_in_egld_before);
+        let equity_after_in_egld =
+            self.saturating_sub_md(total_collateral_in_egld_after, total_debt_in_egld_after);
+        if equity_after_in_egld < equity_before_in_egld {
+            let equity_lost_in_egld = equity_before_in_egld.clone().sub(equity_after_in_egld.clone());
+            let max_equity_lost_in_egld =
+                self.calculate_fee_amount(traded_amount_in_egld, max_slippage_bps);
+            require!(
+                equity_lost_in_egld <= max_equity_lost_in_egld,
+                ERROR_SLIPPAGE_TOO_HIGH
+            );
+        }
+
+        self.event_position_changed(
+            &ltv_ratio_bps_before,
+            &ltv_ratio_bps_after,
+            &equity_before_in_egld,
+            &equity_after_in_egld,
+        );
+    }
+
+    /// Returns `a - b`, or zero if `b` is greater than `a`.
+    fn saturating_sub_md(
+        &self,
+        a: ManagedDecimal<Self::Api, NumDecimals>,
+        b: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        if a > b {
+            a.sub(b)
+        } else {
+            self.md_zero(WAD_PRECISION)
+        }
+    }
+
+    /// Clears the collateral and debt flags once the lending position has none left.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `lending_position_nonce` - Nonce of the position NFT
+    fn refresh_position_flags(&self, lending_address: &ManagedAddress, lending_position_nonce: u64) {
+        let remaining_collateral =
+            self.get_total_supplied_in_egld(lending_address, lending_position_nonce);
+        if !self.is_md_gt_zero(&remaining_collateral) {
+            self.has_collateral().set(false);
+        }
+        let remaining_debt = self.get_total_borrowed_in_egld(lending_address, lending_position_nonce);
+        if !self.is_md_gt_zero(&remaining_debt) {
+            self.has_debt().set(false);
+        }
+    }
+
     // === Events ===
 
     // #[event("deposit")]
@@ -455,6 +1438,15 @@ pub trait BotModule:
     //     #[indexed] share_returned: &ManagedDecimal<Self::Api, NumDecimals>,
     // );
 
+    /// Event emitted when the vault position is leveraged to a target LTV ratio.
+    #[event("leverageToTarget")]
+    fn event_leverage_to_target(
+        &self,
+        #[indexed] target_ltv_ratio_bps: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] flash_loan_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] adjustment_amount_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
     /// Event emitted when a deleverage operation is executed.
     #[event("deleverage")]
     fn event_deleverage(
@@ -463,4 +1455,40 @@ pub trait BotModule:
         #[indexed] collateral_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
         #[indexed] new_debt_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
     );
+
+    /// Event emitted when the vault position is deleveraged through a flash loan.
+    #[event("flashDeleverage")]
+    fn event_flash_deleverage(
+        &self,
+        #[indexed] debt_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] collateral_amount: &BigUint,
+        #[indexed] new_debt_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
+    /// Event emitted when all the debt of the vault position is repaid.
+    #[event("unwindAll")]
+    fn event_unwind_all(
+        &self,
+        #[indexed] debt_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] collateral_amount: &BigUint,
+    );
+
+    /// Event emitted after a bot action with the vault position before and after execution.
+    #[event("positionChanged")]
+    fn event_position_changed(
+        &self,
+        #[indexed] ltv_ratio_bps_before: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] ltv_ratio_bps_after: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] equity_before_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] equity_after_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
+    /// Event emitted when a keeper rebalances the vault position.
+    #[event("rebalance")]
+    fn event_rebalance(
+        &self,
+        #[indexed] keeper: &ManagedAddress,
+        #[indexed] action: BotAction,
+        #[indexed] adjustment_amount_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
 }
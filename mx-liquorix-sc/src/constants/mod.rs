@@ -25,3 +25,52 @@ pub const DEFAULT_SAFE_THRESHOLD_BPS: u64 = 4_000;
 
 /// Default inaction zone in BPS around the safe threshold (5%).
 pub const DEFAULT_INACTION_ZONE_BPS: u64 = 500;
+
+/// Default maximum value lost by a bot action in BPS of the traded amount (1%).
+pub const DEFAULT_MAX_SLIPPAGE_BPS: u64 = 100;
+
+/// Maximum value lost by a bot action in BPS of the traded amount (10%).
+pub const MAX_SLIPPAGE_BPS: u64 = 1_000;
+
+/// Default minimum health factor of the lending position in WAD precision (1.1).
+pub const DEFAULT_MIN_HEALTH_FACTOR: u128 = 1_100_000_000_000_000_000;
+
+/// Lowest configurable minimum health factor in WAD precision (1.0), the liquidation threshold.
+pub const MIN_HEALTH_FACTOR_FLOOR: u128 = WAD;
+
+/// Maximum deposit and withdraw fee in BPS (5%).
+pub const MAX_ENTRY_EXIT_FEE_BPS: u64 = 500;
+
+/// Maximum performance fee in BPS (30%).
+pub const MAX_PERFORMANCE_FEE_BPS: u64 = 3_000;
+
+/// Maximum management fee in BPS per year (5%).
+pub const MAX_MANAGEMENT_FEE_BPS: u64 = 500;
+
+/// Maximum early exit penalty in BPS (10%).
+pub const MAX_EARLY_EXIT_PENALTY_BPS: u64 = 1_000;
+
+/// Maximum keeper reward in BPS of the amount rebalanced (1%).
+pub const MAX_KEEPER_REWARD_BPS: u64 = 100;
+
+/// Default maximum value lost by a keeper rebalance in BPS of the traded amount (0.2%).
+pub const DEFAULT_MAX_KEEPER_SLIPPAGE_BPS: u64 = 20;
+
+/// Maximum value lost by a keeper rebalance in BPS of the traded amount (1%).
+pub const MAX_KEEPER_SLIPPAGE_BPS: u64 = 100;
+
+/// Milliseconds in a year (365 days), used to annualize the management fee.
+pub const MILLIS_PER_YEAR: u64 = 31_536_000_000;
+
+/// Minimum supplied amount (1 xEGLD) for the first deposit into an empty vault.
+pub const MIN_FIRST_DEPOSIT_AMOUNT: u128 = WAD;
+
+/// Shares minted to the contract on the first deposit and locked forever,
+/// so the share supply never returns to zero.
+pub const DEAD_SHARES: u128 = 1_000;
+
+/// Endpoint receiving the flash loan taken by `leverageToTarget`.
+pub const FLASH_LOAN_LEVERAGE_CALLBACK: &[u8] = b"flashLoanLeverageCallback";
+
+/// Endpoint receiving the flash loan taken by `flashDeleverage` and `unwindAll`.
+pub const FLASH_LOAN_DELEVERAGE_CALLBACK: &[u8] = b"flashLoanDeleverageCallback";
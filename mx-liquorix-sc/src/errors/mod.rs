@@ -22,12 +22,24 @@ pub static ERROR_DELEGATE_FAILED: &[u8] =
 
 /// Error when depositing an unsupported token.
 pub static ERROR_INVALID_DEPOSIT_TOKEN: &[u8] =
-    b"Unsupported token: Only EGLD deposits are accepted.";
+    b"Unsupported token: Only EGLD and xEGLD are accepted without swap arguments.";
 
 /// Error when deposit amount is zero or invalid.
 pub static ERROR_INVALID_DEPOSIT_AMOUNT: &[u8] =
     b"Invalid deposit: Amount must be greater than zero.";
 
+/// Error when a deposit carries an unexpected number of payments.
+pub static ERROR_INVALID_DEPOSIT_PAYMENTS: &[u8] =
+    b"Invalid deposit: Expected the deposit payment and at most one share token to merge.";
+
+/// Error when a deposit merges a share token into a position sent to another address.
+pub static ERROR_MERGE_RECIPIENT_NOT_CALLER: &[u8] =
+    b"Invalid deposit: A share token can only be merged when the caller is the recipient.";
+
+/// Error when a deposit swap returns an invalid or zero amount.
+pub static ERROR_INVALID_SWAPPED_AMOUNT: &[u8] =
+    b"Swap error: Received invalid or zero amount from swap router.";
+
 /// Error when delegated amount returned is invalid.
 pub static ERROR_INVALID_DELEGATED_AMOUNT: &[u8] =
     b"Delegation error: Received invalid or zero amount from staking protocol.";
@@ -94,3 +106,149 @@ pub static ERROR_INVALID_COLLATERAL_TO_WITHDRAW_WRT_DEBT: &[u8] =
 /// Error when collateral is insufficient to cover the user's share of debt.
 pub static ERROR_INSUFFICIENT_COLLATERAL_FOR_DEBT: &[u8] =
     b"Insufficient collateral: User's share of collateral is less than their share of debt.";
+
+/// Error when merging share tokens without at least two share token payments.
+pub static ERROR_INVALID_MERGE_PAYMENTS: &[u8] =
+    b"Invalid merge: At least two share token payments are required.";
+
+/// Error when split amounts are zero or do not add up to the share token payment.
+pub static ERROR_INVALID_SPLIT_AMOUNTS: &[u8] =
+    b"Invalid split: At least two non-zero amounts summing up to the share token payment are required.";
+
+/// Error when a swap returns less than the requested minimum amount.
+pub static ERROR_SWAP_AMOUNT_BELOW_MIN: &[u8] =
+    b"Slippage exceeded: Swapped amount is below the requested minimum amount out.";
+
+/// Error when a deposit mints fewer shares than the requested minimum.
+pub static ERROR_DEPOSIT_SHARES_BELOW_MIN: &[u8] =
+    b"Slippage exceeded: Minted shares are below the requested minimum shares out.";
+
+/// Error when a withdrawal returns less than the requested minimum amount.
+pub static ERROR_WITHDRAW_AMOUNT_BELOW_MIN: &[u8] =
+    b"Slippage exceeded: Withdrawn amount is below the requested minimum amount out.";
+
+/// Error when the deposit or withdraw fee exceeds the maximum.
+pub static ERROR_ENTRY_EXIT_FEE_TOO_HIGH: &[u8] =
+    b"Invalid fee: Deposit and withdraw fees must be <= 5% (500 BPS).";
+
+/// Error when the performance fee exceeds the maximum.
+pub static ERROR_PERFORMANCE_FEE_TOO_HIGH: &[u8] =
+    b"Invalid fee: Performance fee must be <= 30% (3000 BPS).";
+
+/// Error when the management fee exceeds the maximum.
+pub static ERROR_MANAGEMENT_FEE_TOO_HIGH: &[u8] =
+    b"Invalid fee: Management fee must be <= 5% (500 BPS) per year.";
+
+/// Error when claiming fees before the treasury address is set.
+pub static ERROR_TREASURY_NOT_SET: &[u8] =
+    b"Treasury not set: A treasury address is required to claim fees.";
+
+/// Error when a non-treasury address attempts a treasury-only operation.
+pub static ERROR_NOT_TREASURY: &[u8] =
+    b"Access denied: This operation can only be performed by the treasury or the owner.";
+
+/// Error when there are no accrued fee shares to claim.
+pub static ERROR_NO_FEES_TO_CLAIM: &[u8] = b"Nothing to claim: No fee shares have accrued.";
+
+/// Error when a deposit would exceed the maximum vault equity.
+pub static ERROR_VAULT_DEPOSIT_CAP_EXCEEDED: &[u8] =
+    b"Deposit cap exceeded: The deposit would exceed the maximum vault equity.";
+
+/// Error when a deposit would exceed the maximum deposit per address.
+pub static ERROR_ADDRESS_DEPOSIT_CAP_EXCEEDED: &[u8] =
+    b"Deposit cap exceeded: The deposit would exceed the maximum deposit per address.";
+
+/// Error when a deposit is made by an address that is not allowlisted.
+pub static ERROR_NOT_ALLOWLISTED: &[u8] =
+    b"Access denied: Deposits are restricted to allowlisted addresses.";
+
+/// Error when the first deposit into an empty vault is below the minimum.
+pub static ERROR_FIRST_DEPOSIT_TOO_SMALL: &[u8] =
+    b"Invalid deposit: The first deposit must be at least 1 xEGLD.";
+
+/// Error when a share token payment does not cover the shares needed for an exact withdrawal.
+pub static ERROR_INSUFFICIENT_SHARES_FOR_AMOUNT: &[u8] =
+    b"Insufficient shares: The share token payment does not cover the requested amount.";
+
+/// Error when an in-kind withdrawal does not repay the debt share of the redeemed shares.
+pub static ERROR_INSUFFICIENT_DEBT_REPAYMENT: &[u8] =
+    b"Insufficient repayment: The borrowed token payment does not cover the debt share.";
+
+/// Error when an in-kind withdrawal receives more than one borrowed token payment.
+pub static ERROR_INVALID_REPAYMENT_PAYMENTS: &[u8] =
+    b"Invalid repayment: At most one borrowed token payment is accepted.";
+
+/// Error when the early exit penalty exceeds the maximum.
+pub static ERROR_EARLY_EXIT_PENALTY_TOO_HIGH: &[u8] =
+    b"Invalid penalty: Early exit penalty must be <= 10% (1000 BPS).";
+
+/// Error when shares are withdrawn before the minimum holding period without an early exit penalty.
+pub static ERROR_HOLDING_PERIOD_NOT_ELAPSED: &[u8] =
+    b"Holding period not elapsed: Shares cannot be withdrawn before the minimum holding period.";
+
+/// Error when querying a share token nonce the address does not hold.
+pub static ERROR_SHARE_TOKEN_NOT_HELD: &[u8] =
+    b"Invalid share token: The address does not hold this share token nonce.";
+
+/// Error when leveraging to a target LTV ratio that is not above the current one.
+pub static ERROR_LTV_RATIO_NOT_BELOW_TARGET: &[u8] =
+    b"Invalid target LTV ratio: The current LTV ratio is already at or above the target.";
+
+/// Error when a flash loan callback is not part of a flash loan taken by the vault.
+pub static ERROR_FLASH_LOAN_NOT_ONGOING: &[u8] =
+    b"Access denied: This endpoint can only be called during a flash loan taken by the vault.";
+
+/// Error when a flash loan callback does not receive the borrowed token.
+pub static ERROR_INVALID_FLASH_LOAN_PAYMENT: &[u8] =
+    b"Invalid flash loan: A single borrowed token payment is expected.";
+
+/// Error when a flash loan leaves a surplus after repaying all debt without a route to EGLD.
+pub static ERROR_SURPLUS_SWAP_ARGS_REQUIRED: &[u8] =
+    b"Invalid swap args: A swap route to EGLD is required for the surplus once all debt is repaid.";
+
+/// Error when a flash loan callback cannot send back the loan plus the flash loan fee.
+pub static ERROR_FLASH_LOAN_REPAYMENT_NOT_COVERED: &[u8] =
+    b"Insufficient swap output: The swapped amount does not cover the flash loan repayment.";
+
+/// Error when the debt to repay exceeds the vault debt.
+pub static ERROR_INVALID_DEBT_TO_REPAY: &[u8] =
+    b"Invalid debt to repay: Must be <= the total debt of the vault.";
+
+/// Error when unwinding a vault without debt.
+pub static ERROR_NO_DEBT_TO_UNWIND: &[u8] = b"Nothing to unwind: The vault has no debt.";
+
+/// Error when the keeper reward exceeds the maximum.
+pub static ERROR_KEEPER_REWARD_TOO_HIGH: &[u8] =
+    b"Invalid reward: Keeper reward must be <= 1% (100 BPS) of the rebalanced amount.";
+
+/// Error when the maximum slippage of the keeper rebalances exceeds the maximum.
+pub static ERROR_MAX_KEEPER_SLIPPAGE_TOO_HIGH: &[u8] =
+    b"Invalid slippage: Maximum keeper slippage must be <= 1% (100 BPS).";
+
+/// Error when rebalancing while the LTV ratio is within the inaction zone.
+pub static ERROR_NO_REBALANCE_NEEDED: &[u8] =
+    b"Nothing to rebalance: The LTV ratio is within the inaction zone.";
+
+/// Error when the maximum slippage of the bot actions exceeds the maximum.
+pub static ERROR_MAX_SLIPPAGE_TOO_HIGH: &[u8] =
+    b"Invalid slippage: Maximum slippage must be <= 10% (1000 BPS).";
+
+/// Error when a bot action loses more equity than the maximum slippage allows.
+pub static ERROR_SLIPPAGE_TOO_HIGH: &[u8] =
+    b"Slippage exceeded: Equity lost by the bot action exceeds the maximum slippage.";
+
+/// Error when the LTV ratio after a bot action is outside the suggested zone.
+pub static ERROR_LTV_RATIO_OUT_OF_BOUNDS: &[u8] =
+    b"Invalid LTV ratio: The LTV ratio after execution is outside the suggested zone bounds.";
+
+/// Error when a deleverage repays less debt than the requested minimum.
+pub static ERROR_REPAID_AMOUNT_BELOW_MIN: &[u8] =
+    b"Slippage exceeded: Repaid debt is below the requested minimum amount.";
+
+/// Error when the minimum health factor is below the liquidation threshold.
+pub static ERROR_MIN_HEALTH_FACTOR_TOO_LOW: &[u8] =
+    b"Invalid health factor: Minimum health factor must be >= 1 (WAD).";
+
+/// Error when an operation leaves the lending position below the minimum health factor.
+pub static ERROR_HEALTH_FACTOR_BELOW_MIN: &[u8] =
+    b"Unsafe position: The health factor of the lending position is below the minimum.";
@@ -19,7 +19,10 @@
 
 use multiversx_sc::imports::*;
 
-use crate::constants::{DEFAULT_INACTION_ZONE_BPS, DEFAULT_SAFE_THRESHOLD_BPS, WAD_PRECISION};
+use crate::constants::{
+    DEFAULT_INACTION_ZONE_BPS, DEFAULT_MAX_KEEPER_SLIPPAGE_BPS, DEFAULT_MAX_SLIPPAGE_BPS,
+    DEFAULT_MIN_HEALTH_FACTOR, DEFAULT_SAFE_THRESHOLD_BPS, WAD_PRECISION,
+};
 
 pub mod bot;
 pub mod constants;
@@ -38,6 +41,7 @@ pub mod xoxno;
 pub trait Liquorix:
     system::manage::ManageModule
     + system::admins::AdminsModule
+    + system::allowlist::AllowlistModule
     + system::pause::PauseModule
     + vault::VaultModule
     + xoxno::liquid_staking::LiquidStakingModule
@@ -47,6 +51,12 @@ pub trait Liquorix:
     + system::utils::UtilsModule
     + system::storage::StorageModule
     + system::attributes::AttributesModule
+    + system::fees::FeesModule
+    + system::caps::CapsModule
+    + system::holding_period::HoldingPeriodModule
+    + system::health::HealthModule
+    + system::keeper::KeeperModule
+    + system::positions::PositionsModule
 {
     /// Initializes the smart contract with required configuration.
     ///
@@ -81,11 +91,27 @@ pub trait Liquorix:
                 BigUint::zero(),
                 WAD_PRECISION,
             ));
+        self.high_water_mark()
+            .set_if_empty(ManagedDecimal::from_raw_units(
+                BigUint::zero(),
+                WAD_PRECISION,
+            ));
+        self.accrued_fee_shares()
+            .set_if_empty(ManagedDecimal::from_raw_units(
+                BigUint::zero(),
+                WAD_PRECISION,
+            ));
 
         self.safe_threshold_bps()
             .set_if_empty(DEFAULT_SAFE_THRESHOLD_BPS);
         self.inaction_zone_bps()
             .set_if_empty(DEFAULT_INACTION_ZONE_BPS);
+        self.max_slippage_bps()
+            .set_if_empty(DEFAULT_MAX_SLIPPAGE_BPS);
+        self.max_keeper_slippage_bps()
+            .set_if_empty(DEFAULT_MAX_KEEPER_SLIPPAGE_BPS);
+        self.min_health_factor()
+            .set_if_empty(BigUint::from(DEFAULT_MIN_HEALTH_FACTOR));
 
         self.admins().insert(self.caller());
     }
@@ -98,6 +124,22 @@ pub trait Liquorix:
                 BigUint::zero(),
                 WAD_PRECISION,
             ));
+        self.high_water_mark()
+            .set_if_empty(ManagedDecimal::from_raw_units(
+                BigUint::zero(),
+                WAD_PRECISION,
+            ));
+        self.accrued_fee_shares()
+            .set_if_empty(ManagedDecimal::from_raw_units(
+                BigUint::zero(),
+                WAD_PRECISION,
+            ));
+        self.max_slippage_bps()
+            .set_if_empty(DEFAULT_MAX_SLIPPAGE_BPS);
+        self.max_keeper_slippage_bps()
+            .set_if_empty(DEFAULT_MAX_KEEPER_SLIPPAGE_BPS);
+        self.min_health_factor()
+            .set_if_empty(BigUint::from(DEFAULT_MIN_HEALTH_FACTOR));
     }
 
     // #[only_owner]
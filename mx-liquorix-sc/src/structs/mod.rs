@@ -29,6 +29,8 @@ pub struct SystemInfo<M: ManagedTypeApi> {
     pub swap_router: ManagedAddress<M>,
     pub bot_address: ManagedAddress<M>,
     pub admins: ManagedVec<M, ManagedAddress<M>>,
+    pub high_water_mark: ManagedDecimal<M, NumDecimals>,
+    pub accrued_fee_shares: ManagedDecimal<M, NumDecimals>,
 }
 impl<M: ManagedTypeApi> SystemInfo<M> {
     pub fn new(
@@ -46,6 +48,8 @@ impl<M: ManagedTypeApi> SystemInfo<M> {
         swap_router: ManagedAddress<M>,
         bot_address: ManagedAddress<M>,
         admins: ManagedVec<M, ManagedAddress<M>>,
+        high_water_mark: ManagedDecimal<M, NumDecimals>,
+        accrued_fee_shares: ManagedDecimal<M, NumDecimals>,
     ) -> Self {
         SystemInfo {
             paused,
@@ -62,11 +66,13 @@ impl<M: ManagedTypeApi> SystemInfo<M> {
             swap_router,
             bot_address,
             admins,
+            high_water_mark,
+            accrued_fee_shares,
         }
     }
 
     pub fn into_multi_value(self) -> SystemInfoType<M> {
-        MultiValue14::from((
+        MultiValue16::from((
             self.paused,
             self.share_token,
             self.total_shares,
@@ -81,12 +87,14 @@ impl<M: ManagedTypeApi> SystemInfo<M> {
             self.swap_router,
             self.bot_address,
             MultiValueEncoded::from(self.admins),
+            self.high_water_mark,
+            self.accrued_fee_shares,
         ))
     }
 }
 
 /// Type alias for system info returned as MultiValue for ABI compatibility.
-pub type SystemInfoType<M> = MultiValue14<
+pub type SystemInfoType<M> = MultiValue16<
     bool,
     EsdtTokenIdentifier<M>,
     ManagedDecimal<M, NumDecimals>,
@@ -101,6 +109,8 @@ pub type SystemInfoType<M> = MultiValue14<
     ManagedAddress<M>,
     ManagedAddress<M>,
     MultiValueEncoded<M, ManagedAddress<M>>,
+    ManagedDecimal<M, NumDecimals>,
+    ManagedDecimal<M, NumDecimals>,
 >;
 
 /// Attributes stored in each share token NFT.
@@ -341,3 +351,127 @@ pub type BotInfoType<M> = MultiValue10<
     ManagedDecimal<M, NumDecimals>,
     bool,
 >;
+
+/// Contains the amounts involved in redeeming share tokens.
+///
+/// Returned by the withdraw preview views and used by the withdraw endpoints:
+/// - Redeemed shares after the withdraw fee:
+///   `redeemed_share_amount = share_amount - withdraw_fee_shares`
+/// - Collateral share:
+///   `collateral_amount = total_collateral * redeemed_share_amount / total_shares`
+/// - Debt share:
+///   `debt_repaid_in_egld = total_debt_in_egld * redeemed_share_amount / total_shares`
+/// - Collateral swapped to repay the debt share:
+///   `repayment_collateral_amount = debt_repaid_in_egld / supplied_token_price_in_egld`
+/// - Net collateral (xEGLD) paid out:
+///   `net_amount = collateral_amount - repayment_collateral_amount`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct WithdrawAmounts<M: ManagedTypeApi> {
+    pub share_amount: ManagedDecimal<M, NumDecimals>,
+    pub redeemed_share_amount: ManagedDecimal<M, NumDecimals>,
+    pub collateral_amount: ManagedDecimal<M, NumDecimals>,
+    pub debt_repaid_in_egld: ManagedDecimal<M, NumDecimals>,
+    pub repayment_collateral_amount: ManagedDecimal<M, NumDecimals>,
+    pub net_amount: ManagedDecimal<M, NumDecimals>,
+}
+impl<M: ManagedTypeApi> WithdrawAmounts<M> {
+    pub fn new(
+        share_amount: ManagedDecimal<M, NumDecimals>,
+        redeemed_share_amount: ManagedDecimal<M, NumDecimals>,
+        collateral_amount: ManagedDecimal<M, NumDecimals>,
+        debt_repaid_in_egld: ManagedDecimal<M, NumDecimals>,
+        repayment_collateral_amount: ManagedDecimal<M, NumDecimals>,
+        net_amount: ManagedDecimal<M, NumDecimals>,
+    ) -> Self {
+        WithdrawAmounts {
+            share_amount,
+            redeemed_share_amount,
+            collateral_amount,
+            debt_repaid_in_egld,
+            repayment_collateral_amount,
+            net_amount,
+        }
+    }
+}
+
+/// Contains the PnL (Profit and Loss) of a share token position.
+///
+/// Values are computed on the vault equity, so the debt is taken into account:
+/// - Initial value, the initially supplied xEGLD at the current xEGLD price:
+///   `initial_value_in_egld = share_amount * total_initial_supplied_amount / total_share_supply * supplied_token_price_in_egld`
+/// - Current value, the position's share of the equity:
+///   `current_value_in_egld = share_amount * (total_supplied_in_egld - total_debt_in_egld) / total_shares`
+/// - PnL:
+///   `pnl_in_egld = current_value_in_egld - initial_value_in_egld`
+/// - PnL percentage:
+///   `pnl_percentage = pnl_in_egld * 100 / initial_value_in_egld`
+///
+/// USD values are the EGLD values at the EGLD price in USD.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct PositionPnl<M: ManagedTypeApi> {
+    pub nonce: u64,
+    pub share_amount: ManagedDecimal<M, NumDecimals>,
+    pub initial_value_in_egld: ManagedDecimal<M, NumDecimals>,
+    pub current_value_in_egld: ManagedDecimal<M, NumDecimals>,
+    pub pnl_in_egld: ManagedDecimalSigned<M, NumDecimals>,
+    pub initial_value_in_usd: ManagedDecimal<M, NumDecimals>,
+    pub current_value_in_usd: ManagedDecimal<M, NumDecimals>,
+    pub pnl_in_usd: ManagedDecimalSigned<M, NumDecimals>,
+    pub pnl_percentage: ManagedDecimalSigned<M, NumDecimals>,
+}
+impl<M: ManagedTypeApi> PositionPnl<M> {
+    pub fn new(
+        nonce: u64,
+        share_amount: ManagedDecimal<M, NumDecimals>,
+        initial_value_in_egld: ManagedDecimal<M, NumDecimals>,
+        current_value_in_egld: ManagedDecimal<M, NumDecimals>,
+        pnl_in_egld: ManagedDecimalSigned<M, NumDecimals>,
+        initial_value_in_usd: ManagedDecimal<M, NumDecimals>,
+        current_value_in_usd: ManagedDecimal<M, NumDecimals>,
+        pnl_in_usd: ManagedDecimalSigned<M, NumDecimals>,
+        pnl_percentage: ManagedDecimalSigned<M, NumDecimals>,
+    ) -> Self {
+        PositionPnl {
+            nonce,
+            share_amount,
+            initial_value_in_egld,
+            current_value_in_egld,
+            pnl_in_egld,
+            initial_value_in_usd,
+            current_value_in_usd,
+            pnl_in_usd,
+            pnl_percentage,
+        }
+    }
+}
+
+/// Contains a share token position held by an address.
+///
+/// The nonce, balance, current equity value and PnL of the position are part of `pnl`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct AccountPosition<M: ManagedTypeApi> {
+    pub attributes: ShareTokenAttributes<M>,
+    pub pnl: PositionPnl<M>,
+}
+impl<M: ManagedTypeApi> AccountPosition<M> {
+    pub fn new(attributes: ShareTokenAttributes<M>, pnl: PositionPnl<M>) -> Self {
+        AccountPosition { attributes, pnl }
+    }
+}
+
+/// Enum representing the kind of fee charged to the vault.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub enum FeeType {
+    /// Fee on the shares minted for a deposit.
+    Deposit,
+    /// Fee on the shares redeemed by a withdrawal.
+    Withdraw,
+    /// Fee on the share price gains above the high-water mark.
+    Performance,
+    /// Time-based fee on the whole vault.
+    Management,
+}
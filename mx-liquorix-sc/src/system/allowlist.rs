@@ -0,0 +1,116 @@
+//! # Allowlist Module
+//!
+//! Provides an optional deposit allowlist for private vault instances.
+//!
+//! When the allowlist is enabled, only allowlisted addresses can deposit.
+//! Withdrawals are never restricted.
+
+use crate::errors::ERROR_NOT_ALLOWLISTED;
+use multiversx_sc::imports::*;
+
+/// Smart Contract module that offers a deposit allowlist.
+///
+/// It provides:
+/// * an endpoint where an admin can enable/disable the allowlist
+/// * two endpoints where an admin can add/remove allowlisted addresses
+/// * a method to require an address to be allowed to deposit
+#[multiversx_sc::module]
+pub trait AllowlistModule:
+    crate::system::admins::AdminsModule + crate::system::storage::StorageModule
+{
+    // === Endpoints ===
+
+    /// Enables or disables the deposit allowlist.
+    ///
+    /// # Access Control
+    /// Only callable by an admin.
+    ///
+    /// # Arguments
+    /// - `enabled` - Whether deposits are restricted to allowlisted addresses
+    #[endpoint(setAllowlistEnabled)]
+    fn set_allowlist_enabled(&self, enabled: bool) {
+        self.require_is_admin(&self.blockchain().get_caller());
+
+        self.allowlist_enabled().set(enabled);
+        self.event_allowlist_enabled_set(enabled);
+    }
+
+    /// Adds one or more addresses to the allowlist.
+    ///
+    /// # Access Control
+    /// Only callable by an admin.
+    ///
+    /// # Arguments
+    /// - `addresses` - List of addresses to allow
+    #[endpoint(addToAllowlist)]
+    fn add_to_allowlist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        self.event_allowlist_added(&addresses);
+
+        for address in addresses.into_iter() {
+            self.allowlist().insert(address);
+        }
+    }
+
+    /// Removes one or more addresses from the allowlist.
+    ///
+    /// # Access Control
+    /// Only callable by an admin.
+    ///
+    /// # Arguments
+    /// - `addresses` - List of addresses to remove
+    #[endpoint(removeFromAllowlist)]
+    fn remove_from_allowlist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        self.event_allowlist_removed(&addresses);
+
+        for address in addresses.into_iter() {
+            self.allowlist().swap_remove(&address);
+        }
+    }
+
+    // === Views ===
+
+    /// Returns whether deposits are restricted to allowlisted addresses.
+    #[view(isAllowlistEnabled)]
+    fn is_allowlist_enabled(&self) -> bool {
+        self.allowlist_enabled().get()
+    }
+
+    /// Returns whether the address can deposit under the current allowlist settings.
+    #[view(canDeposit)]
+    fn can_deposit(&self, address: ManagedAddress) -> bool {
+        !self.allowlist_enabled().get() || self.allowlist().contains(&address)
+    }
+
+    /// Returns all allowlisted addresses.
+    #[view(getAllowlist)]
+    fn get_allowlist(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.allowlist().iter().collect()
+    }
+
+    // === Private ===
+
+    /// Requires the given address to be allowed to deposit.
+    ///
+    /// # Panics
+    /// Panics with `ERROR_NOT_ALLOWLISTED` if the allowlist is enabled and
+    /// the address is not on it.
+    fn require_can_deposit(&self, address: &ManagedAddress) {
+        require!(self.can_deposit(address.clone()), ERROR_NOT_ALLOWLISTED);
+    }
+
+    // === Events ===
+
+    /// Emitted when the allowlist is enabled or disabled.
+    #[event("allowlistEnabledSet")]
+    fn event_allowlist_enabled_set(&self, #[indexed] enabled: bool);
+
+    /// Emitted when addresses are added to the allowlist.
+    #[event("allowlistAdded")]
+    fn event_allowlist_added(&self, #[indexed] addresses: &MultiValueEncoded<ManagedAddress>);
+
+    /// Emitted when addresses are removed from the allowlist.
+    #[event("allowlistRemoved")]
+    fn event_allowlist_removed(&self, #[indexed] addresses: &MultiValueEncoded<ManagedAddress>);
+}
@@ -79,6 +79,40 @@ pub trait AttributesModule:
         attributes
     }
 
+    /// Merges two sets of share token attributes into one.
+    ///
+    /// Share supplies and initial supplied amounts are summed, so PnL stays
+    /// continuous. The last interaction timestamp is the latest of the two, so
+    /// merging fresh shares into old ones does not shorten their holding period.
+    ///
+    /// # Arguments
+    /// - `first` - Attributes of the first share position
+    /// - `second` - Attributes of the second share position
+    fn merge_share_attributes(
+        &self,
+        first: &ShareTokenAttributes<Self::Api>,
+        second: &ShareTokenAttributes<Self::Api>,
+    ) -> ShareTokenAttributes<Self::Api> {
+        let total_share_supply = first
+            .total_share_supply
+            .clone()
+            .add(second.total_share_supply.clone());
+        let total_initial_supplied_amount = first
+            .total_initial_supplied_amount
+            .clone()
+            .add(second.total_initial_supplied_amount.clone());
+
+        let last_interaction_ts_millis = first
+            .last_interaction_ts_millis
+            .max(second.last_interaction_ts_millis);
+
+        ShareTokenAttributes::new(
+            total_share_supply,
+            total_initial_supplied_amount,
+            last_interaction_ts_millis,
+        )
+    }
+
     /// Decodes share token attributes from a buffer.
     ///
     /// # Arguments
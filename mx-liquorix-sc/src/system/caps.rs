@@ -0,0 +1,189 @@
+//! # Caps Module
+//!
+//! Handles the deposit caps of the vault.
+//!
+//! Two caps can be configured by the owner:
+//! - A maximum vault equity in EGLD, checked against the equity after the deposit
+//! - A maximum deposit per address in EGLD, tracked as the cumulative value
+//!   of the deposits credited to an address
+//!
+//! The per-address cap is a deposit-only limit: shares can be transferred, so
+//! withdrawals do not release the tracked amount.
+//!
+//! A cap set to zero is disabled. Deposits exceeding a cap are reverted.
+
+use multiversx_sc::imports::*;
+
+use crate::{
+    constants::WAD_PRECISION,
+    errors::{ERROR_ADDRESS_DEPOSIT_CAP_EXCEEDED, ERROR_VAULT_DEPOSIT_CAP_EXCEEDED},
+};
+
+/// Module for the vault deposit caps.
+///
+/// Provides:
+/// - Owner configuration of the vault and per-address caps
+/// - Cap enforcement and per-address deposit tracking
+/// - A view exposing the caps and the remaining capacity
+#[multiversx_sc::module]
+pub trait CapsModule:
+    crate::system::storage::StorageModule
+    + crate::system::utils::UtilsModule
+    + crate::xoxno::lending::LendingModule
+{
+    // === Endpoints ===
+
+    /// Sets the deposit caps. A value of zero disables the cap.
+    ///
+    /// # Arguments
+    /// - `max_vault_equity_in_egld` - Maximum vault equity (collateral minus debt) in EGLD
+    /// - `max_deposit_per_address` - Maximum cumulative deposit value per address in EGLD
+    #[only_owner]
+    #[endpoint(setDepositCaps)]
+    fn set_deposit_caps(
+        &self,
+        max_vault_equity_in_egld: BigUint,
+        max_deposit_per_address: BigUint,
+    ) {
+        self.event_deposit_caps_set(&max_vault_equity_in_egld, &max_deposit_per_address);
+
+        self.max_vault_equity_in_egld()
+            .set(max_vault_equity_in_egld);
+        self.max_deposit_per_address().set(max_deposit_per_address);
+    }
+
+    // === Views ===
+
+    /// Returns the deposit caps and the remaining capacity for an address as
+    /// `(max_vault_equity_in_egld, remaining_vault_capacity_in_egld,
+    /// max_deposit_per_address, remaining_address_capacity)`.
+    ///
+    /// A cap of zero is disabled, and its remaining capacity is reported as zero.
+    #[view(getDepositCaps)]
+    fn get_deposit_caps(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValue4<BigUint, BigUint, BigUint, BigUint> {
+        let max_vault_equity_in_egld = self.max_vault_equity_in_egld().get();
+        let remaining_vault_capacity_in_egld = if self.is_biguint_gt_zero(&max_vault_equity_in_egld)
+        {
+            let vault_equity_in_egld = self.md_to_biguint(&self.get_vault_equity_in_egld());
+            self.saturating_sub_biguint(&max_vault_equity_in_egld, &vault_equity_in_egld)
+        } else {
+            self.biguint_zero()
+        };
+
+        let max_deposit_per_address = self.max_deposit_per_address().get();
+        let remaining_address_capacity = if self.is_biguint_gt_zero(&max_deposit_per_address) {
+            self.saturating_sub_biguint(
+                &max_deposit_per_address,
+                &self.deposited_amount(&address).get(),
+            )
+        } else {
+            self.biguint_zero()
+        };
+
+        (
+            max_vault_equity_in_egld,
+            remaining_vault_capacity_in_egld,
+            max_deposit_per_address,
+            remaining_address_capacity,
+        )
+            .into()
+    }
+
+    /// Returns the cumulative deposit value in EGLD credited to an address.
+    #[view(getDepositedAmount)]
+    fn get_deposited_amount(&self, address: ManagedAddress) -> BigUint {
+        self.deposited_amount(&address).get()
+    }
+
+    // === Private ===
+
+    /// Requires the vault equity after the deposit to stay within the vault cap.
+    ///
+    /// # Arguments
+    /// - `deposit_value_in_egld` - Value of the deposit in EGLD
+    /// - `vault_equity_in_egld` - Vault equity in EGLD before the deposit
+    fn require_within_vault_cap(
+        &self,
+        deposit_value_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        vault_equity_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let max_vault_equity_in_egld = self.max_vault_equity_in_egld().get();
+        if self.is_biguint_eq_zero(&max_vault_equity_in_egld) {
+            return;
+        }
+
+        require!(
+            self.md_to_biguint(
+                &vault_equity_in_egld
+                    .clone()
+                    .add(deposit_value_in_egld.clone())
+            ) <= max_vault_equity_in_egld,
+            ERROR_VAULT_DEPOSIT_CAP_EXCEEDED
+        );
+    }
+
+    /// Records a deposit for an address and requires it to stay within the per-address cap.
+    ///
+    /// # Arguments
+    /// - `address` - Address credited with the deposit
+    /// - `deposit_value_in_egld` - Value of the deposit in EGLD
+    fn track_address_deposit(
+        &self,
+        address: &ManagedAddress,
+        deposit_value_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let new_deposited_amount =
+            self.deposited_amount(address).get() + self.md_to_biguint(deposit_value_in_egld);
+
+        let max_deposit_per_address = self.max_deposit_per_address().get();
+        require!(
+            self.is_biguint_eq_zero(&max_deposit_per_address)
+                || new_deposited_amount <= max_deposit_per_address,
+            ERROR_ADDRESS_DEPOSIT_CAP_EXCEEDED
+        );
+
+        self.deposited_amount(address).set(new_deposited_amount);
+    }
+
+    /// Returns the vault equity (collateral minus debt) in EGLD, or zero without a position.
+    fn get_vault_equity_in_egld(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let lending_position_nonce = self.lending_position_nonce().get();
+        if lending_position_nonce == 0 {
+            return self.md_zero(WAD_PRECISION);
+        }
+
+        let lending_address = self.lending_address().get();
+        let total_supplied_in_egld =
+            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+        let total_borrowed_in_egld =
+            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+
+        if total_supplied_in_egld > total_borrowed_in_egld {
+            total_supplied_in_egld.sub(total_borrowed_in_egld)
+        } else {
+            self.md_zero(WAD_PRECISION)
+        }
+    }
+
+    /// Returns `a - b`, or zero if `b` is greater than `a`.
+    fn saturating_sub_biguint(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        if a > b {
+            a - b
+        } else {
+            self.biguint_zero()
+        }
+    }
+
+    // === Events ===
+
+    /// Emitted when the deposit caps are set.
+    #[event("depositCapsSet")]
+    fn event_deposit_caps_set(
+        &self,
+        #[indexed] max_vault_equity_in_egld: &BigUint,
+        #[indexed] max_deposit_per_address: &BigUint,
+    );
+}
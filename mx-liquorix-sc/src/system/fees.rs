@@ -0,0 +1,518 @@
+//! # Fees Module
+//!
+//! Handles the vault fees paid to the treasury.
+//!
+//! Fees are charged by minting shares instead of moving collateral, so the
+//! lending position is never touched. Fee shares are added to `total_shares`
+//! and accrued until the treasury claims them as a share token.
+//!
+//! The management fee accrues continuously over time and is crystallized on
+//! every deposit, withdrawal and bot action.
+
+use multiversx_sc::imports::*;
+
+use crate::{
+    constants::{
+        BPS, BPS_PRECISION, MAX_ENTRY_EXIT_FEE_BPS, MAX_MANAGEMENT_FEE_BPS,
+        MAX_PERFORMANCE_FEE_BPS, MILLIS_PER_YEAR, WAD, WAD_PRECISION,
+    },
+    errors::{
+        ERROR_ENTRY_EXIT_FEE_TOO_HIGH, ERROR_MANAGEMENT_FEE_TOO_HIGH, ERROR_NOT_TREASURY,
+        ERROR_NO_FEES_TO_CLAIM, ERROR_PERFORMANCE_FEE_TOO_HIGH, ERROR_TREASURY_NOT_SET,
+    },
+    structs::FeeType,
+};
+
+/// Module for the vault fee subsystem.
+///
+/// Provides:
+/// - Deposit and withdraw fees in BPS of the shares minted or redeemed
+/// - Performance fee in BPS of the share price gains above the high-water mark
+/// - Management fee in BPS per year, accrued from block timestamps
+/// - Fee share accrual and claiming by the treasury
+#[multiversx_sc::module]
+pub trait FeesModule:
+    crate::system::storage::StorageModule
+    + crate::system::utils::UtilsModule
+    + crate::system::attributes::AttributesModule
+    + crate::system::positions::PositionsModule
+    + crate::xoxno::lending::LendingModule
+{
+    // === Endpoints ===
+
+    /// Sets the treasury address receiving the fee shares.
+    #[only_owner]
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, address: ManagedAddress) {
+        self.event_treasury_address_set(&address);
+        self.treasury_address().set(address);
+    }
+
+    /// Sets the vault fees.
+    ///
+    /// # Arguments
+    /// - `deposit_fee_bps` - Fee in BPS of the shares minted on deposit
+    /// - `withdraw_fee_bps` - Fee in BPS of the shares redeemed on withdraw
+    /// - `performance_fee_bps` - Fee in BPS of the share price gains above the high-water mark
+    ///
+    /// # Validation
+    /// - `deposit_fee_bps` and `withdraw_fee_bps` must be <= `MAX_ENTRY_EXIT_FEE_BPS`
+    /// - `performance_fee_bps` must be <= `MAX_PERFORMANCE_FEE_BPS`
+    #[only_owner]
+    #[endpoint(setFees)]
+    fn set_fees(&self, deposit_fee_bps: u64, withdraw_fee_bps: u64, performance_fee_bps: u64) {
+        require!(
+            deposit_fee_bps <= MAX_ENTRY_EXIT_FEE_BPS && withdraw_fee_bps <= MAX_ENTRY_EXIT_FEE_BPS,
+            ERROR_ENTRY_EXIT_FEE_TOO_HIGH
+        );
+        require!(
+            performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
+            ERROR_PERFORMANCE_FEE_TOO_HIGH
+        );
+
+        self.deposit_fee_bps().set(deposit_fee_bps);
+        self.withdraw_fee_bps().set(withdraw_fee_bps);
+        self.performance_fee_bps().set(performance_fee_bps);
+
+        self.event_fees_set(deposit_fee_bps, withdraw_fee_bps, performance_fee_bps);
+    }
+
+    /// Sets the annualized management fee.
+    ///
+    /// The management fee accrued at the previous rate is crystallized first.
+    ///
+    /// # Arguments
+    /// - `management_fee_bps` - Fee in BPS of the vault per year
+    ///
+    /// # Validation
+    /// - `management_fee_bps` must be <= `MAX_MANAGEMENT_FEE_BPS`
+    #[only_owner]
+    #[endpoint(setManagementFee)]
+    fn set_management_fee(&self, management_fee_bps: u64) {
+        require!(
+            management_fee_bps <= MAX_MANAGEMENT_FEE_BPS,
+            ERROR_MANAGEMENT_FEE_TOO_HIGH
+        );
+
+        self.charge_management_fee();
+        self.management_fee_bps().set(management_fee_bps);
+
+        self.event_management_fee_set(management_fee_bps);
+    }
+
+    /// Claims the accrued fee shares.
+    ///
+    /// Mints a share token holding all accrued fee shares and sends it to the treasury.
+    /// The shares are already part of `total_shares`, so the share price is unchanged.
+    ///
+    /// # Access Control
+    /// Only callable by the treasury or the owner.
+    ///
+    /// # Returns
+    /// The share token sent to the treasury.
+    #[endpoint(claimFees)]
+    fn claim_fees(&self) -> EsdtTokenPayment {
+        require!(!self.treasury_address().is_empty(), ERROR_TREASURY_NOT_SET);
+
+        let treasury = self.treasury_address().get();
+        let caller = self.caller();
+        require!(
+            caller == treasury || caller == self.owner(),
+            ERROR_NOT_TREASURY
+        );
+
+        let fee_shares = self.accrued_fee_shares().get();
+        require!(self.is_md_gt_zero(&fee_shares), ERROR_NO_FEES_TO_CLAIM);
+
+        self.accrued_fee_shares().set(self.md_zero(WAD_PRECISION));
+        self.event_fees_claimed(&treasury, &fee_shares);
+
+        let fee_share_payment = self.share_token().nft_create_and_send(
+            &treasury,
+            self.md_to_biguint(&fee_shares),
+            &self.attributes_to_buffer(
+                fee_shares,
+                self.md_zero(WAD_PRECISION),
+                self.current_ts_millis(),
+            ),
+        );
+        self.register_account_position(&treasury, &fee_share_payment);
+
+        fee_share_payment
+    }
+
+    // === Views ===
+
+    /// Returns the fees in BPS as `(deposit_fee_bps, withdraw_fee_bps, performance_fee_bps)`.
+    #[view(getFees)]
+    fn get_fees(&self) -> MultiValue3<u64, u64, u64> {
+        (
+            self.deposit_fee_bps().get(),
+            self.withdraw_fee_bps().get(),
+            self.performance_fee_bps().get(),
+        )
+            .into()
+    }
+
+    /// Returns the management fee shares accrued since the last crystallization.
+    ///
+    /// These shares are not yet part of `total_shares`; they are minted on the
+    /// next deposit, withdrawal or bot action.
+    #[view(getAccruedManagementFee)]
+    fn get_accrued_management_fee(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
+        self.calculate_pending_management_fee_shares(self.total_shares().get())
+    }
+
+    /// Returns the management fee in BPS per year.
+    #[view(getManagementFee)]
+    fn get_management_fee(&self) -> u64 {
+        self.management_fee_bps().get()
+    }
+
+    /// Returns the treasury address.
+    #[view(getTreasuryAddress)]
+    fn get_treasury_address(&self) -> ManagedAddress {
+        self.treasury_address().get()
+    }
+
+    // === Private ===
+
+    /// Crystallizes the time-based and performance fees before the vault state changes.
+    fn crystallize_fees(&self) {
+        self.charge_management_fee();
+        self.charge_performance_fee();
+    }
+
+    /// Charges the management fee accrued since the last crystallization.
+    ///
+    /// Nothing accrues while the vault holds no shares.
+    fn charge_management_fee(&self) {
+        let fee_shares = self.calculate_pending_management_fee_shares(self.total_shares().get());
+        self.last_management_fee_ts_millis()
+            .set(self.current_ts_millis());
+
+        self.accrue_fee_shares(FeeType::Management, &fee_shares);
+    }
+
+    /// Charges the deposit fee on newly minted shares.
+    ///
+    /// # Arguments
+    /// - `share_amount` - Shares minted for the deposit
+    ///
+    /// # Returns
+    /// The shares left for the depositor after the fee.
+    fn charge_deposit_fee(
+        &self,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let fee_shares = self.calculate_deposit_fee_shares(share_amount.clone());
+        self.accrue_fee_shares(FeeType::Deposit, &fee_shares);
+
+        share_amount.sub(fee_shares)
+    }
+
+    /// Charges the withdraw fee on redeemed shares.
+    ///
+    /// # Arguments
+    /// - `share_amount` - Shares sent for withdrawal
+    ///
+    /// # Returns
+    /// The shares actually redeemed for collateral after the fee.
+    fn charge_withdraw_fee(
+        &self,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let fee_shares = self.calculate_withdraw_fee_shares(share_amount.clone());
+        self.accrue_fee_shares(FeeType::Withdraw, &fee_shares);
+
+        share_amount.sub(fee_shares)
+    }
+
+    /// Charges the performance fee on share price gains above the high-water mark.
+    ///
+    /// The share price is `(total_supplied_in_egld - total_debt_in_egld) / total_shares`.
+    /// The first time a price is observed, it only becomes the high-water mark.
+    /// After charging, the high-water mark is set to the diluted share price.
+    fn charge_performance_fee(&self) {
+        let total_shares = self.total_shares().get();
+        if !self.is_md_gt_zero(&total_shares) {
+            return;
+        }
+
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let total_supplied_in_egld =
+            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+        let total_debt_in_egld =
+            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+        if total_supplied_in_egld <= total_debt_in_egld {
+            return;
+        }
+
+        let equity_in_egld = total_supplied_in_egld.sub(total_debt_in_egld);
+        let share_price = self.calculate_share_price(&equity_in_egld, &total_shares);
+        let high_water_mark = self.high_water_mark().get();
+
+        if !self.is_md_gt_zero(&high_water_mark) {
+            self.high_water_mark().set(share_price);
+            return;
+        }
+
+        if share_price <= high_water_mark {
+            return;
+        }
+
+        let fee_shares =
+            self.calculate_pending_performance_fee_shares(&total_shares, &equity_in_egld);
+        self.accrue_fee_shares(FeeType::Performance, &fee_shares);
+
+        self.high_water_mark()
+            .set(self.calculate_share_price(&equity_in_egld, &total_shares.add(fee_shares)));
+    }
+
+    /// Returns the total share supply once the pending management and performance
+    /// fees are crystallized, without changing any state.
+    ///
+    /// Mirrors `crystallize_fees` for the preview views.
+    ///
+    /// # Arguments
+    /// - `total_supplied_in_egld` - Vault collateral in EGLD
+    /// - `total_debt_in_egld` - Vault debt in EGLD
+    fn get_total_shares_after_pending_fees(
+        &self,
+        total_supplied_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        total_debt_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let total_shares = self.total_shares().get();
+        let total_shares = total_shares
+            .clone()
+            .add(self.calculate_pending_management_fee_shares(total_shares));
+
+        if !self.is_md_gt_zero(&total_shares) || total_supplied_in_egld <= total_debt_in_egld {
+            return total_shares;
+        }
+
+        let equity_in_egld = total_supplied_in_egld
+            .clone()
+            .sub(total_debt_in_egld.clone());
+        let fee_shares =
+            self.calculate_pending_performance_fee_shares(&total_shares, &equity_in_egld);
+
+        total_shares.add(fee_shares)
+    }
+
+    /// Calculates the deposit fee shares taken from newly minted shares.
+    fn calculate_deposit_fee_shares(
+        &self,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        self.calculate_fee_amount(share_amount, self.deposit_fee_bps().get())
+    }
+
+    /// Calculates the withdraw fee shares taken from redeemed shares.
+    fn calculate_withdraw_fee_shares(
+        &self,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        self.calculate_fee_amount(share_amount, self.withdraw_fee_bps().get())
+    }
+
+    /// Calculates the management fee shares accrued since the last crystallization.
+    ///
+    /// Nothing is due before the accrual clock has started.
+    fn calculate_pending_management_fee_shares(
+        &self,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let last_ts_millis = self.last_management_fee_ts_millis().get();
+        if last_ts_millis == 0 {
+            return self.md_zero(WAD_PRECISION);
+        }
+
+        self.calculate_management_fee_shares(
+            total_shares,
+            self.management_fee_bps().get(),
+            self.current_ts_millis().saturating_sub(last_ts_millis),
+        )
+    }
+
+    /// Calculates the performance fee shares due at the current share price.
+    ///
+    /// Nothing is due before a high-water mark is set, or while the share
+    /// price is not above it.
+    ///
+    /// # Arguments
+    /// - `total_shares` - Current total share supply
+    /// - `equity_in_egld` - Vault equity (collateral minus debt) in EGLD
+    fn calculate_pending_performance_fee_shares(
+        &self,
+        total_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+        equity_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let high_water_mark = self.high_water_mark().get();
+        let share_price = self.calculate_share_price(equity_in_egld, total_shares);
+
+        if !self.is_md_gt_zero(&high_water_mark) || share_price <= high_water_mark {
+            return self.md_zero(WAD_PRECISION);
+        }
+
+        self.calculate_performance_fee_shares(
+            total_shares.clone(),
+            equity_in_egld.clone(),
+            share_price,
+            high_water_mark,
+            self.performance_fee_bps().get(),
+        )
+    }
+
+    /// Calculates the share price in EGLD.
+    ///
+    /// Uses the formula: share_price = equity_in_egld / total_shares
+    fn calculate_share_price(
+        &self,
+        equity_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        total_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        equity_in_egld
+            .clone()
+            .mul(self.wad_as_md())
+            .div(total_shares.clone())
+    }
+
+    /// Adds fee shares to the treasury accrual and to the total share supply.
+    fn accrue_fee_shares(
+        &self,
+        fee_type: FeeType,
+        fee_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        if !self.is_md_gt_zero(fee_shares) {
+            return;
+        }
+
+        self.accrued_fee_shares()
+            .update(|accrued| *accrued += fee_shares);
+        self.total_shares()
+            .update(|total_shares| *total_shares += fee_shares);
+
+        self.event_fees_charged(fee_type, fee_shares);
+    }
+
+    /// Calculates the fee part of an amount.
+    ///
+    /// Uses the formula: fee = amount * fee_bps / BPS
+    fn calculate_fee_amount(
+        &self,
+        amount: ManagedDecimal<Self::Api, NumDecimals>,
+        fee_bps: u64,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        amount
+            .mul(self.biguint_to_md(BigUint::from(fee_bps), BPS_PRECISION))
+            .div(self.biguint_to_md(BigUint::from(BPS), BPS_PRECISION))
+    }
+
+    /// Calculates the shares to mint for the performance fee.
+    ///
+    /// The fee value is `(share_price - high_water_mark) * total_shares * fee_bps / BPS`.
+    /// Shares are minted so that they are worth the fee value after dilution:
+    /// `fee_shares = fee_value * total_shares / (equity - fee_value)`
+    ///
+    /// # Arguments
+    /// - `total_shares` - Current total share supply
+    /// - `equity_in_egld` - Vault equity (collateral minus debt) in EGLD
+    /// - `share_price` - Current share price in EGLD
+    /// - `high_water_mark` - Highest share price fees were charged at
+    /// - `fee_bps` - Performance fee in BPS
+    fn calculate_performance_fee_shares(
+        &self,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        equity_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+        share_price: ManagedDecimal<Self::Api, NumDecimals>,
+        high_water_mark: ManagedDecimal<Self::Api, NumDecimals>,
+        fee_bps: u64,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let gain_in_egld = share_price
+            .sub(high_water_mark)
+            .mul(total_shares.clone())
+            .div(self.wad_as_md());
+        let fee_in_egld = self.calculate_fee_amount(gain_in_egld, fee_bps);
+
+        if fee_in_egld >= equity_in_egld {
+            return self.md_zero(WAD_PRECISION);
+        }
+
+        fee_in_egld
+            .clone()
+            .mul(total_shares)
+            .div(equity_in_egld.sub(fee_in_egld))
+    }
+
+    /// Calculates the shares to mint for the management fee.
+    ///
+    /// The treasury is due `fee_ratio = fee_bps * elapsed_millis / (BPS * MILLIS_PER_YEAR)`
+    /// of the vault. Shares are minted so that they own that ratio after dilution:
+    /// `fee_shares = total_shares * fee_ratio / (1 - fee_ratio)`
+    ///
+    /// # Arguments
+    /// - `total_shares` - Current total share supply
+    /// - `fee_bps` - Management fee in BPS per year
+    /// - `elapsed_millis` - Time since the last crystallization
+    fn calculate_management_fee_shares(
+        &self,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        fee_bps: u64,
+        elapsed_millis: u64,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let fee_ratio = self.biguint_to_md(
+            BigUint::from(fee_bps) * elapsed_millis * BigUint::from(WAD)
+                / (BigUint::from(BPS) * MILLIS_PER_YEAR),
+            WAD_PRECISION,
+        );
+
+        if fee_ratio >= self.wad_as_md() {
+            return self.md_zero(WAD_PRECISION);
+        }
+
+        total_shares
+            .mul(fee_ratio.clone())
+            .div(self.wad_as_md().sub(fee_ratio))
+    }
+
+    // === Events ===
+
+    /// Emitted when the treasury address is set.
+    #[event("treasuryAddressSet")]
+    fn event_treasury_address_set(&self, #[indexed] address: &ManagedAddress);
+
+    /// Emitted when the fees are set.
+    #[event("feesSet")]
+    fn event_fees_set(
+        &self,
+        #[indexed] deposit_fee_bps: u64,
+        #[indexed] withdraw_fee_bps: u64,
+        #[indexed] performance_fee_bps: u64,
+    );
+
+    /// Emitted when the management fee is set.
+    #[event("managementFeeSet")]
+    fn event_management_fee_set(&self, #[indexed] management_fee_bps: u64);
+
+    /// Emitted when fee shares are charged.
+    ///
+    /// # Indexed Parameters
+    /// - `fee_type` - The kind of fee charged
+    /// - `fee_shares` - Amount of shares minted to the treasury
+    #[event("feesCharged")]
+    fn event_fees_charged(
+        &self,
+        #[indexed] fee_type: FeeType,
+        #[indexed] fee_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
+    /// Emitted when the treasury claims the accrued fee shares.
+    #[event("feesClaimed")]
+    fn event_fees_claimed(
+        &self,
+        #[indexed] treasury: &ManagedAddress,
+        #[indexed] fee_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+}
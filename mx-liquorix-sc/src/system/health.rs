@@ -0,0 +1,111 @@
+//! # Health Module
+//!
+//! Handles the minimum health factor of the lending position.
+//!
+//! The health factor reported by the lending protocol falls below 1 when the
+//! position can be liquidated. Deposits, withdrawals and leverage operations
+//! are rejected when they leave the position below the minimum, while
+//! deleverage operations may then bypass the LTV zone bounds to restore it.
+//!
+//! This is a safety net independent of the LTV ratio thresholds, which are
+//! computed from oracle prices and ignore the liquidation thresholds.
+
+use multiversx_sc::imports::*;
+
+use crate::{
+    constants::{MIN_HEALTH_FACTOR_FLOOR, WAD_PRECISION},
+    errors::{ERROR_HEALTH_FACTOR_BELOW_MIN, ERROR_MIN_HEALTH_FACTOR_TOO_LOW},
+};
+
+/// Module for the minimum health factor.
+///
+/// Provides:
+/// - Owner configuration of the minimum health factor
+/// - Health factor checks of the lending position
+#[multiversx_sc::module]
+pub trait HealthModule:
+    crate::system::storage::StorageModule
+    + crate::system::utils::UtilsModule
+    + crate::xoxno::lending::LendingModule
+{
+    // === Endpoints ===
+
+    /// Sets the minimum health factor of the lending position.
+    ///
+    /// # Arguments
+    /// - `min_health_factor` - Minimum health factor in WAD precision (e.g. 1.1e18 = 1.1)
+    ///
+    /// # Validation
+    /// - `min_health_factor` must be >= `MIN_HEALTH_FACTOR_FLOOR`
+    #[only_owner]
+    #[endpoint(setMinHealthFactor)]
+    fn set_min_health_factor(&self, min_health_factor: BigUint) {
+        require!(
+            min_health_factor >= BigUint::from(MIN_HEALTH_FACTOR_FLOOR),
+            ERROR_MIN_HEALTH_FACTOR_TOO_LOW
+        );
+
+        self.event_min_health_factor_set(&min_health_factor);
+
+        self.min_health_factor().set(min_health_factor);
+    }
+
+    // === Views ===
+
+    /// Returns the minimum health factor in WAD precision.
+    #[view(getMinHealthFactor)]
+    fn get_min_health_factor(&self) -> BigUint {
+        self.min_health_factor().get()
+    }
+
+    // === Private ===
+
+    /// Returns whether the lending position is below the minimum health factor.
+    ///
+    /// A position without debt cannot be liquidated and is never below the minimum.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `lending_position_nonce` - Nonce of the position NFT
+    fn is_below_min_health_factor(
+        &self,
+        lending_address: &ManagedAddress,
+        lending_position_nonce: u64,
+    ) -> bool {
+        if !self.has_debt().get() {
+            return false;
+        }
+
+        let health_factor = self
+            .get_health_factor(lending_address, lending_position_nonce)
+            .rescale(WAD_PRECISION);
+        let min_health_factor = self.biguint_to_md(self.min_health_factor().get(), WAD_PRECISION);
+
+        health_factor < min_health_factor
+    }
+
+    /// Ensures the lending position is at or above the minimum health factor.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `lending_position_nonce` - Nonce of the position NFT
+    ///
+    /// # Panics
+    /// Panics if the health factor is below the minimum.
+    fn require_min_health_factor(
+        &self,
+        lending_address: &ManagedAddress,
+        lending_position_nonce: u64,
+    ) {
+        require!(
+            !self.is_below_min_health_factor(lending_address, lending_position_nonce),
+            ERROR_HEALTH_FACTOR_BELOW_MIN
+        );
+    }
+
+    // === Events ===
+
+    /// Emitted when the minimum health factor is set.
+    #[event("minHealthFactorSet")]
+    fn event_min_health_factor_set(&self, #[indexed] min_health_factor: &BigUint);
+}
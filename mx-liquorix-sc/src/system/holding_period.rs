@@ -0,0 +1,220 @@
+//! # Holding Period Module
+//!
+//! Handles the minimum holding period of the vault shares.
+//!
+//! Shares redeemed before `last_interaction_ts_millis + min_holding_period_millis`
+//! are either blocked, or charged an early exit penalty when one is configured.
+//! Penalty shares are burned without redeeming collateral, so their value goes
+//! to the remaining holders. The interaction timestamp is only set when shares
+//! are minted or merged, so a withdrawal leaves the remaining shares unchanged.
+//!
+//! This prevents depositing and withdrawing around bot rebalances to capture
+//! their value from long-term holders.
+
+use multiversx_sc::imports::*;
+
+use crate::{
+    constants::{MAX_EARLY_EXIT_PENALTY_BPS, WAD_PRECISION},
+    errors::{
+        ERROR_EARLY_EXIT_PENALTY_TOO_HIGH, ERROR_HOLDING_PERIOD_NOT_ELAPSED,
+        ERROR_SHARE_TOKEN_NOT_HELD,
+    },
+    structs::{ShareTokenAttributes, TimestampInMillis},
+};
+
+/// Module for the share holding period.
+///
+/// Provides:
+/// - Owner configuration of the minimum holding period and the early exit penalty
+/// - Early exit penalty calculation for redeemed share tokens
+/// - Views exposing the configuration and the end of the holding period of a share token
+#[multiversx_sc::module]
+pub trait HoldingPeriodModule:
+    crate::system::storage::StorageModule
+    + crate::system::utils::UtilsModule
+    + crate::system::attributes::AttributesModule
+    + crate::system::fees::FeesModule
+    + crate::system::positions::PositionsModule
+    + crate::xoxno::lending::LendingModule
+{
+    // === Endpoints ===
+
+    /// Sets the minimum holding period and the early exit penalty.
+    ///
+    /// # Arguments
+    /// - `min_holding_period_millis` - Minimum time between the last interaction of a
+    ///   share token and its withdrawal. Zero disables the holding period.
+    /// - `early_exit_penalty_bps` - Penalty in BPS of the shares redeemed early.
+    ///   Zero blocks early withdrawals instead.
+    ///
+    /// # Validation
+    /// - `early_exit_penalty_bps` must be <= `MAX_EARLY_EXIT_PENALTY_BPS`
+    #[only_owner]
+    #[endpoint(setHoldingPeriod)]
+    fn set_holding_period(&self, min_holding_period_millis: u64, early_exit_penalty_bps: u64) {
+        require!(
+            early_exit_penalty_bps <= MAX_EARLY_EXIT_PENALTY_BPS,
+            ERROR_EARLY_EXIT_PENALTY_TOO_HIGH
+        );
+
+        self.event_holding_period_set(min_holding_period_millis, early_exit_penalty_bps);
+
+        self.min_holding_period_millis()
+            .set(min_holding_period_millis);
+        self.early_exit_penalty_bps().set(early_exit_penalty_bps);
+    }
+
+    // === Views ===
+
+    /// Returns the holding period as `(min_holding_period_millis, early_exit_penalty_bps)`.
+    #[view(getHoldingPeriod)]
+    fn get_holding_period(&self) -> MultiValue2<u64, u64> {
+        (
+            self.min_holding_period_millis().get(),
+            self.early_exit_penalty_bps().get(),
+        )
+            .into()
+    }
+
+    /// Returns the timestamp in milliseconds from which a share token held by an
+    /// address can be withdrawn without penalty.
+    ///
+    /// # Arguments
+    /// - `address` - Address holding the share token
+    /// - `share_token_nonce` - Nonce of the share token
+    ///
+    /// # Panics
+    /// Panics if the address does not hold the share token nonce.
+    #[view(getHoldingPeriodEnd)]
+    fn get_holding_period_end(
+        &self,
+        address: ManagedAddress,
+        share_token_nonce: u64,
+    ) -> TimestampInMillis {
+        let share_balance = self.blockchain().get_esdt_balance(
+            &address,
+            &self.share_token().get_token_id(),
+            share_token_nonce,
+        );
+        require!(
+            self.is_biguint_gt_zero(&share_balance),
+            ERROR_SHARE_TOKEN_NOT_HELD
+        );
+
+        let share_attributes =
+            self.get_share_token_attributes_from_address(share_token_nonce, &address);
+        self.calculate_holding_period_end(&share_attributes)
+    }
+
+    // === Private ===
+
+    /// Charges the early exit penalty on share token payments still in their holding period.
+    ///
+    /// The penalty shares are only excluded from the redeemed shares. They are burned
+    /// with the rest of the payment, which leaves their value to the remaining holders.
+    ///
+    /// # Arguments
+    /// - `share_payments` - Share token payments being redeemed
+    ///
+    /// # Returns
+    /// The penalty shares.
+    ///
+    /// # Panics
+    /// Panics with `ERROR_HOLDING_PERIOD_NOT_ELAPSED` if a payment is still in its
+    /// holding period and no early exit penalty is configured.
+    fn charge_early_exit_penalty(
+        &self,
+        share_payments: &ManagedVec<EsdtTokenPayment>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut penalty_shares = self.md_zero(WAD_PRECISION);
+        if self.min_holding_period_millis().get() == 0 {
+            return penalty_shares;
+        }
+
+        for share_payment in share_payments.iter() {
+            penalty_shares += &self.calculate_early_exit_penalty_shares(
+                &self.get_share_token_attributes(share_payment.token_nonce),
+                self.biguint_to_md(share_payment.amount.clone(), WAD_PRECISION),
+            );
+        }
+
+        if self.is_md_gt_zero(&penalty_shares) {
+            self.event_early_exit_penalty_charged(&penalty_shares);
+        }
+
+        penalty_shares
+    }
+
+    /// Calculates the early exit penalty on redeemed shares of a share token.
+    ///
+    /// # Arguments
+    /// - `share_attributes` - Attributes of the share token
+    /// - `share_amount` - Amount of shares redeemed
+    ///
+    /// # Returns
+    /// The penalty shares, zero once the holding period has elapsed.
+    ///
+    /// # Panics
+    /// Panics with `ERROR_HOLDING_PERIOD_NOT_ELAPSED` if the share token is still in its
+    /// holding period and no early exit penalty is configured.
+    fn calculate_early_exit_penalty_shares(
+        &self,
+        share_attributes: &ShareTokenAttributes<Self::Api>,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        if self.current_ts_millis() >= self.calculate_holding_period_end(share_attributes) {
+            return self.md_zero(WAD_PRECISION);
+        }
+
+        let early_exit_penalty_bps = self.early_exit_penalty_bps().get();
+        require!(early_exit_penalty_bps > 0, ERROR_HOLDING_PERIOD_NOT_ELAPSED);
+
+        self.calculate_fee_amount(share_amount, early_exit_penalty_bps)
+    }
+
+    /// Requires the share token to be out of its holding period.
+    ///
+    /// # Panics
+    /// Panics with `ERROR_HOLDING_PERIOD_NOT_ELAPSED` if it is not.
+    fn require_holding_period_elapsed(&self, share_token_nonce: u64) {
+        require!(
+            !self.is_within_holding_period(share_token_nonce),
+            ERROR_HOLDING_PERIOD_NOT_ELAPSED
+        );
+    }
+
+    /// Returns whether the share token is still in its holding period.
+    fn is_within_holding_period(&self, share_token_nonce: u64) -> bool {
+        if self.min_holding_period_millis().get() == 0 {
+            return false;
+        }
+
+        let share_attributes = self.get_share_token_attributes(share_token_nonce);
+        self.current_ts_millis() < self.calculate_holding_period_end(&share_attributes)
+    }
+
+    /// Returns the timestamp in milliseconds at which the holding period of share token attributes ends.
+    fn calculate_holding_period_end(
+        &self,
+        share_attributes: &ShareTokenAttributes<Self::Api>,
+    ) -> TimestampInMillis {
+        share_attributes.last_interaction_ts_millis + self.min_holding_period_millis().get()
+    }
+
+    // === Events ===
+
+    /// Emitted when the holding period is set.
+    #[event("holdingPeriodSet")]
+    fn event_holding_period_set(
+        &self,
+        #[indexed] min_holding_period_millis: u64,
+        #[indexed] early_exit_penalty_bps: u64,
+    );
+
+    /// Emitted when an early exit penalty is charged.
+    #[event("earlyExitPenaltyCharged")]
+    fn event_early_exit_penalty_charged(
+        &self,
+        #[indexed] penalty_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+}
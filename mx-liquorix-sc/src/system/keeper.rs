@@ -0,0 +1,202 @@
+//! # Keeper Module
+//!
+//! Handles the reward paid to keepers calling the permissionless `rebalance`.
+//!
+//! The reward is a share in BPS of the rebalanced amount, capped at a maximum
+//! value in EGLD. It is paid by minting shares to the keeper, so it is taken
+//! from the vault equity without touching the lending position.
+//!
+//! A reward or cap set to zero disables the reward.
+//!
+//! Keepers choose the swap route of a rebalance, so its equity lost is bounded
+//! by a maximum keeper slippage, tighter than the one of the bot actions.
+
+use multiversx_sc::imports::*;
+
+use crate::{
+    constants::{MAX_KEEPER_REWARD_BPS, MAX_KEEPER_SLIPPAGE_BPS, WAD_PRECISION},
+    errors::{ERROR_KEEPER_REWARD_TOO_HIGH, ERROR_MAX_KEEPER_SLIPPAGE_TOO_HIGH},
+};
+
+/// Module for the keeper reward.
+///
+/// Provides:
+/// - Owner configuration of the keeper reward and its cap
+/// - Owner configuration of the maximum keeper slippage
+/// - Keeper reward calculation for a rebalanced amount
+/// - Keeper reward payment as a share token
+#[multiversx_sc::module]
+pub trait KeeperModule:
+    crate::system::storage::StorageModule
+    + crate::system::utils::UtilsModule
+    + crate::system::attributes::AttributesModule
+    + crate::system::caps::CapsModule
+    + crate::system::fees::FeesModule
+    + crate::system::positions::PositionsModule
+    + crate::xoxno::lending::LendingModule
+{
+    // === Endpoints ===
+
+    /// Sets the keeper reward. A value of zero disables the reward.
+    ///
+    /// # Arguments
+    /// - `keeper_reward_bps` - Reward in BPS of the amount rebalanced, in EGLD
+    /// - `max_keeper_reward_in_egld` - Maximum reward per rebalance in EGLD
+    ///
+    /// # Validation
+    /// - `keeper_reward_bps` must be <= `MAX_KEEPER_REWARD_BPS`
+    #[only_owner]
+    #[endpoint(setKeeperReward)]
+    fn set_keeper_reward(&self, keeper_reward_bps: u64, max_keeper_reward_in_egld: BigUint) {
+        require!(
+            keeper_reward_bps <= MAX_KEEPER_REWARD_BPS,
+            ERROR_KEEPER_REWARD_TOO_HIGH
+        );
+
+        self.event_keeper_reward_set(keeper_reward_bps, &max_keeper_reward_in_egld);
+
+        self.keeper_reward_bps().set(keeper_reward_bps);
+        self.max_keeper_reward_in_egld()
+            .set(max_keeper_reward_in_egld);
+    }
+
+    /// Sets the maximum value lost by a keeper rebalance.
+    ///
+    /// # Arguments
+    /// - `max_keeper_slippage_bps` - Maximum equity lost in BPS of the traded amount
+    ///
+    /// # Validation
+    /// - `max_keeper_slippage_bps` must be <= `MAX_KEEPER_SLIPPAGE_BPS`
+    #[only_owner]
+    #[endpoint(setMaxKeeperSlippage)]
+    fn set_max_keeper_slippage(&self, max_keeper_slippage_bps: u64) {
+        require!(
+            max_keeper_slippage_bps <= MAX_KEEPER_SLIPPAGE_BPS,
+            ERROR_MAX_KEEPER_SLIPPAGE_TOO_HIGH
+        );
+
+        self.event_max_keeper_slippage_set(max_keeper_slippage_bps);
+
+        self.max_keeper_slippage_bps().set(max_keeper_slippage_bps);
+    }
+
+    // === Views ===
+
+    /// Returns the keeper reward as `(keeper_reward_bps, max_keeper_reward_in_egld)`.
+    #[view(getKeeperReward)]
+    fn get_keeper_reward(&self) -> MultiValue2<u64, BigUint> {
+        (
+            self.keeper_reward_bps().get(),
+            self.max_keeper_reward_in_egld().get(),
+        )
+            .into()
+    }
+
+    /// Returns the maximum value lost by a keeper rebalance in BPS of the traded amount.
+    #[view(getMaxKeeperSlippage)]
+    fn get_max_keeper_slippage(&self) -> u64 {
+        self.max_keeper_slippage_bps().get()
+    }
+
+    // === Private ===
+
+    /// Calculates the keeper reward for a rebalanced amount.
+    ///
+    /// Uses the formula: reward = min(amount * keeper_reward_bps / BPS, max_keeper_reward_in_egld)
+    ///
+    /// # Arguments
+    /// - `rebalanced_amount_in_egld` - Amount rebalanced in EGLD
+    fn calculate_keeper_reward_in_egld(
+        &self,
+        rebalanced_amount_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let max_keeper_reward_in_egld =
+            self.biguint_to_md(self.max_keeper_reward_in_egld().get(), WAD_PRECISION);
+        let keeper_reward_in_egld = self
+            .calculate_fee_amount(rebalanced_amount_in_egld, self.keeper_reward_bps().get())
+            .rescale(WAD_PRECISION);
+
+        if keeper_reward_in_egld > max_keeper_reward_in_egld {
+            max_keeper_reward_in_egld
+        } else {
+            keeper_reward_in_egld
+        }
+    }
+
+    /// Pays the keeper reward by minting shares to the keeper.
+    ///
+    /// Shares are minted so that they are worth the reward after dilution:
+    /// `reward_shares = reward * total_shares / (equity - reward)`
+    ///
+    /// Nothing is paid while the vault holds no shares, or if the reward is not
+    /// below the vault equity.
+    ///
+    /// # Arguments
+    /// - `keeper` - Address receiving the reward
+    /// - `keeper_reward_in_egld` - Reward value in EGLD
+    fn pay_keeper_reward(
+        &self,
+        keeper: &ManagedAddress,
+        keeper_reward_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let total_shares = self.total_shares().get();
+        let equity_in_egld = self.get_vault_equity_in_egld();
+        if !self.is_md_gt_zero(keeper_reward_in_egld)
+            || !self.is_md_gt_zero(&total_shares)
+            || keeper_reward_in_egld >= &equity_in_egld
+        {
+            return;
+        }
+
+        let reward_shares = keeper_reward_in_egld
+            .clone()
+            .mul(total_shares)
+            .div(equity_in_egld.sub(keeper_reward_in_egld.clone()));
+        if !self.is_md_gt_zero(&reward_shares) {
+            return;
+        }
+
+        self.total_shares()
+            .update(|total_shares| *total_shares += &reward_shares);
+        self.event_keeper_reward_paid(keeper, keeper_reward_in_egld, &reward_shares);
+
+        let reward_share_payment = self.share_token().nft_create_and_send(
+            keeper,
+            self.md_to_biguint(&reward_shares),
+            &self.attributes_to_buffer(
+                reward_shares,
+                self.md_zero(WAD_PRECISION),
+                self.current_ts_millis(),
+            ),
+        );
+        self.register_account_position(keeper, &reward_share_payment);
+    }
+
+    // === Events ===
+
+    /// Emitted when the keeper reward is set.
+    #[event("keeperRewardSet")]
+    fn event_keeper_reward_set(
+        &self,
+        #[indexed] keeper_reward_bps: u64,
+        #[indexed] max_keeper_reward_in_egld: &BigUint,
+    );
+
+    /// Emitted when the maximum keeper slippage is set.
+    #[event("maxKeeperSlippageSet")]
+    fn event_max_keeper_slippage_set(&self, #[indexed] max_keeper_slippage_bps: u64);
+
+    /// Emitted when a keeper is paid for a rebalance.
+    ///
+    /// # Indexed Parameters
+    /// - `keeper` - Address receiving the reward
+    /// - `keeper_reward_in_egld` - Reward value in EGLD
+    /// - `reward_shares` - Amount of shares minted to the keeper
+    #[event("keeperRewardPaid")]
+    fn event_keeper_reward_paid(
+        &self,
+        #[indexed] keeper: &ManagedAddress,
+        #[indexed] keeper_reward_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] reward_shares: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+}
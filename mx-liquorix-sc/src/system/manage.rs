@@ -8,10 +8,10 @@
 use multiversx_sc::imports::*;
 
 use crate::{
-    constants::WAD_PRECISION,
+    constants::{MAX_SLIPPAGE_BPS, WAD_PRECISION},
     errors::{
-        ERROR_INACTION_ZONE_TOO_LARGE, ERROR_INACTION_ZONE_UPPER_BOUND_TOO_HIGH, ERROR_NOT_BOT,
-        ERROR_SAFE_THRESHOLD_TOO_HIGH,
+        ERROR_INACTION_ZONE_TOO_LARGE, ERROR_INACTION_ZONE_UPPER_BOUND_TOO_HIGH,
+        ERROR_MAX_SLIPPAGE_TOO_HIGH, ERROR_NOT_BOT, ERROR_SAFE_THRESHOLD_TOO_HIGH,
     },
     structs::{SystemInfo, SystemInfoType},
 };
@@ -138,6 +138,26 @@ pub trait ManageModule:
         self.event_risk_thresholds_set(safe_threshold_bps, inaction_zone_bps);
     }
 
+    /// Sets the maximum value a bot action can lose to swaps and fees.
+    ///
+    /// # Arguments
+    /// - `max_slippage_bps` - Maximum equity lost in BPS of the traded amount
+    ///
+    /// # Validation
+    /// - `max_slippage_bps` must be <= `MAX_SLIPPAGE_BPS`
+    #[only_owner]
+    #[endpoint(setMaxSlippage)]
+    fn set_max_slippage(&self, max_slippage_bps: u64) {
+        require!(
+            max_slippage_bps <= MAX_SLIPPAGE_BPS,
+            ERROR_MAX_SLIPPAGE_TOO_HIGH
+        );
+
+        self.max_slippage_bps().set(max_slippage_bps);
+
+        self.event_max_slippage_set(max_slippage_bps);
+    }
+
     // === Views ===
 
     /// Returns complete system information as a `SystemInfo` struct.
@@ -158,6 +178,8 @@ pub trait ManageModule:
             self.swap_router_address().get(),
             self.bot_address().get(),
             self.admins().iter().collect(),
+            self.high_water_mark().get(),
+            self.accrued_fee_shares().get(),
         )
     }
 
@@ -167,6 +189,12 @@ pub trait ManageModule:
         self.get_system_info().into_multi_value()
     }
 
+    /// Returns the maximum value a bot action can lose in BPS of the traded amount.
+    #[view(getMaxSlippage)]
+    fn get_max_slippage(&self) -> u64 {
+        self.max_slippage_bps().get()
+    }
+
     // === Private ===
 
     /// Requires the caller to be the authorized bot address.
@@ -219,6 +247,10 @@ pub trait ManageModule:
         #[indexed] inaction_zone_bps: u64,
     );
 
+    /// Emitted when the maximum slippage of the bot actions is set.
+    #[event("maxSlippageSet")]
+    fn event_max_slippage_set(&self, #[indexed] max_slippage_bps: u64);
+
     // === Callbacks ===
 
     /// Callback for share token issuance.
@@ -4,15 +4,29 @@
 //!
 //! Submodules:
 //! - `admins` - Admin management and authorization
+//! - `allowlist` - Deposit allowlist for private vaults
 //! - `attributes` - Share token attribute handling
+//! - `caps` - Vault and per-address deposit caps
+//! - `fees` - Deposit, withdraw and performance fees
+//! - `health` - Minimum health factor of the lending position
+//! - `holding_period` - Minimum share holding period and early exit penalty
+//! - `keeper` - Reward of the keepers calling the permissionless rebalance
 //! - `manage` - Contract configuration and management
 //! - `pause` - Contract pause/unpause functionality
+//! - `positions` - Registry of the share token nonces held by each address
 //! - `storage` - Storage mapper definitions
 //! - `utils` - Utility helper functions
 
 pub mod admins;
+pub mod allowlist;
 pub mod attributes;
+pub mod caps;
+pub mod fees;
+pub mod health;
+pub mod holding_period;
+pub mod keeper;
 pub mod manage;
 pub mod pause;
+pub mod positions;
 pub mod storage;
 pub mod utils;
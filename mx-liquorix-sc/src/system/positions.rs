@@ -0,0 +1,105 @@
+//! # Positions Module
+//!
+//! Keeps a registry of the share token nonces held by each address.
+//!
+//! The registry is updated whenever the vault mints or receives share tokens:
+//! on deposit, withdraw, merge, split and fee claims. A nonce sent to the vault
+//! stays registered for the sender while part of its supply is left, and is
+//! removed once it is fully burned.
+//!
+//! Share tokens can also be transferred directly between addresses, which the
+//! vault cannot observe. The views skip registered nonces no longer held, and
+//! `syncAccountPositions` rebuilds the registry from the share tokens sent to it.
+
+use multiversx_sc::imports::*;
+
+use crate::errors::ERROR_INVALID_SHARE_TOKEN_PAYMENT;
+
+/// Module for the share token positions registry.
+///
+/// Provides:
+/// - Registration of minted share tokens for their receiver
+/// - Registry updates for the share tokens sent to the vault
+/// - An endpoint to rebuild the registry after direct transfers
+#[multiversx_sc::module]
+pub trait PositionsModule:
+    crate::system::storage::StorageModule + crate::system::utils::UtilsModule
+{
+    // === Endpoints ===
+
+    /// Rebuilds the caller's registry from the share tokens sent with the call.
+    ///
+    /// The registered nonces are replaced by the nonces of the payments, and the
+    /// share tokens are sent back. Sending every held share token registers the
+    /// ones received through a direct transfer and removes the ones sent away.
+    ///
+    /// # Payment
+    /// Zero or more share tokens (any nonces).
+    ///
+    /// # Transfers
+    /// Sends the share tokens back to the caller.
+    ///
+    /// # Panics
+    /// - Panics if any payment is not a valid share token
+    #[payable]
+    #[endpoint(syncAccountPositions)]
+    fn sync_account_positions(&self) {
+        let share_payments = self.call_value().all_esdt_transfers().clone();
+        let share_token_id = self.share_token().get_token_id();
+        let caller = self.caller();
+
+        self.account_positions(&caller).clear();
+        for share_payment in share_payments.iter() {
+            require!(
+                share_payment.token_identifier == share_token_id
+                    && self.is_biguint_gt_zero(&share_payment.amount),
+                ERROR_INVALID_SHARE_TOKEN_PAYMENT
+            );
+
+            self.account_positions(&caller)
+                .insert(share_payment.token_nonce);
+        }
+
+        if !share_payments.is_empty() {
+            self.tx().to(&caller).payment(share_payments).transfer();
+        }
+    }
+
+    // === Private ===
+
+    /// Registers a share token minted or sent to an address.
+    ///
+    /// # Arguments
+    /// - `address` - Address receiving the share token
+    /// - `share_payment` - Share token minted or sent to the address
+    fn register_account_position(
+        &self,
+        address: &ManagedAddress,
+        share_payment: &EsdtTokenPayment,
+    ) {
+        self.account_positions(address)
+            .insert(share_payment.token_nonce);
+    }
+
+    /// Updates the registry of an address that sent a share token to be burned.
+    ///
+    /// The nonce is removed once its supply is fully burned, and registered
+    /// otherwise, which also registers share tokens received through a direct transfer.
+    ///
+    /// # Arguments
+    /// - `address` - Address that sent the share token
+    /// - `nonce` - Nonce of the share token
+    /// - `remaining_share_supply` - Supply of the nonce left after the burn
+    fn update_account_position(
+        &self,
+        address: &ManagedAddress,
+        nonce: u64,
+        remaining_share_supply: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        if self.is_md_gt_zero(remaining_share_supply) {
+            self.account_positions(address).insert(nonce);
+        } else {
+            self.account_positions(address).swap_remove(&nonce);
+        }
+    }
+}
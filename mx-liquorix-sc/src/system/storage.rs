@@ -2,7 +2,8 @@
 //!
 //! Defines all storage mappers used by the smart contract.
 //!
-//! Contains storage for tokens, addresses, state flags, and admin list.
+//! Contains storage for tokens, addresses, state flags, admin list, allowlist, fees, deposit caps,
+//! the holding period and the share token positions registry.
 
 use multiversx_sc::imports::*;
 
@@ -65,6 +66,14 @@ pub trait StorageModule {
     #[storage_mapper("admins")]
     fn admins(&self) -> UnorderedSetMapper<ManagedAddress>;
 
+    /// Flag indicating if deposits are restricted to the allowlist.
+    #[storage_mapper("allowlist_enabled")]
+    fn allowlist_enabled(&self) -> SingleValueMapper<bool>;
+
+    /// Set of addresses allowed to deposit while the allowlist is enabled.
+    #[storage_mapper("allowlist")]
+    fn allowlist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     /// Safe threshold for LTV ratio in BPS (basis points).
     #[storage_mapper("safe_threshold_bps")]
     fn safe_threshold_bps(&self) -> SingleValueMapper<u64>;
@@ -72,4 +81,86 @@ pub trait StorageModule {
     /// Inaction zone in BPS around the safe threshold.
     #[storage_mapper("inaction_zone_bps")]
     fn inaction_zone_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Maximum value lost by a bot action in BPS of the traded amount.
+    #[storage_mapper("max_slippage_bps")]
+    fn max_slippage_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Minimum health factor of the lending position in WAD precision.
+    #[storage_mapper("min_health_factor")]
+    fn min_health_factor(&self) -> SingleValueMapper<BigUint>;
+
+    /// Address receiving the fee shares.
+    #[storage_mapper("treasury_address")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Deposit fee in BPS of the shares minted.
+    #[storage_mapper("deposit_fee_bps")]
+    fn deposit_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Withdraw fee in BPS of the shares redeemed.
+    #[storage_mapper("withdraw_fee_bps")]
+    fn withdraw_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Performance fee in BPS of the share price gains above the high-water mark.
+    #[storage_mapper("performance_fee_bps")]
+    fn performance_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Management fee in BPS of the vault per year.
+    #[storage_mapper("management_fee_bps")]
+    fn management_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Timestamp in milliseconds of the last management fee crystallization.
+    #[storage_mapper("last_management_fee_ts_millis")]
+    fn last_management_fee_ts_millis(&self) -> SingleValueMapper<u64>;
+
+    /// Highest share price in EGLD at which the performance fee was charged.
+    #[storage_mapper("high_water_mark")]
+    fn high_water_mark(&self) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Fee shares accrued for the treasury, included in `total_shares`.
+    #[storage_mapper("accrued_fee_shares")]
+    fn accrued_fee_shares(&self) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Maximum vault equity in EGLD accepted by deposits. Zero disables the cap.
+    #[storage_mapper("max_vault_equity_in_egld")]
+    fn max_vault_equity_in_egld(&self) -> SingleValueMapper<BigUint>;
+
+    /// Maximum cumulative deposit value per address in EGLD. Zero disables the cap.
+    #[storage_mapper("max_deposit_per_address")]
+    fn max_deposit_per_address(&self) -> SingleValueMapper<BigUint>;
+
+    /// Cumulative deposit value in EGLD credited to an address. Never released on withdraw.
+    #[storage_mapper("deposited_amount")]
+    fn deposited_amount(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Minimum time in milliseconds between the last interaction of a share token
+    /// and its withdrawal. Zero disables the holding period.
+    #[storage_mapper("min_holding_period_millis")]
+    fn min_holding_period_millis(&self) -> SingleValueMapper<u64>;
+
+    /// Penalty in BPS of the shares withdrawn during their holding period.
+    /// Zero blocks early withdrawals instead.
+    #[storage_mapper("early_exit_penalty_bps")]
+    fn early_exit_penalty_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Share token nonces registered for an address.
+    #[storage_mapper("account_positions")]
+    fn account_positions(&self, address: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    /// Keeper reward in BPS of the amount rebalanced.
+    #[storage_mapper("keeper_reward_bps")]
+    fn keeper_reward_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Maximum keeper reward per rebalance in EGLD.
+    #[storage_mapper("max_keeper_reward_in_egld")]
+    fn max_keeper_reward_in_egld(&self) -> SingleValueMapper<BigUint>;
+
+    /// Maximum value lost by a keeper rebalance in BPS of the traded amount.
+    #[storage_mapper("max_keeper_slippage_bps")]
+    fn max_keeper_slippage_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Whether a flash loan taken by the vault is in progress.
+    #[storage_mapper("flash_loan_ongoing")]
+    fn flash_loan_ongoing(&self) -> SingleValueMapper<bool>;
 }
@@ -144,6 +144,26 @@ pub trait UtilsModule {
         }
     }
 
+    /// Returns `a * b / c` rounded up, with the precision of `a`.
+    ///
+    /// `b` and `c` must share the same precision.
+    ///
+    /// # Arguments
+    /// - `a` - First factor
+    /// - `b` - Second factor
+    /// - `c` - Divisor
+    fn mul_div_ceil_md(
+        &self,
+        a: &ManagedDecimal<Self::Api, NumDecimals>,
+        b: &ManagedDecimal<Self::Api, NumDecimals>,
+        c: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let divisor = c.into_raw_units();
+        let raw = (a.into_raw_units() * b.into_raw_units() + divisor - 1u32) / divisor;
+
+        ManagedDecimal::from_raw_units(raw, a.scale())
+    }
+
     /// Returns the current block timestamp in milliseconds.
     fn current_ts_millis(&self) -> TimestampInMillis {
         self.blockchain()
@@ -7,15 +7,23 @@
 //! their proportional ownership of the vault.
 
 use crate::{
-    constants::WAD_PRECISION,
+    constants::{BPS, DEAD_SHARES, MIN_FIRST_DEPOSIT_AMOUNT, WAD, WAD_PRECISION},
     errors::{
-        ERROR_INSUFFICIENT_COLLATERAL_FOR_DEBT, ERROR_INVALID_COLLATERAL_TO_DEBT_RATIO,
+        ERROR_DEPOSIT_SHARES_BELOW_MIN, ERROR_FIRST_DEPOSIT_TOO_SMALL,
+        ERROR_INSUFFICIENT_COLLATERAL_FOR_DEBT, ERROR_INSUFFICIENT_DEBT_REPAYMENT,
+        ERROR_INSUFFICIENT_SHARES_FOR_AMOUNT, ERROR_INVALID_COLLATERAL_TO_DEBT_RATIO,
         ERROR_INVALID_COLLATERAL_TO_WITHDRAW, ERROR_INVALID_DELEGATED_AMOUNT,
-        ERROR_INVALID_DEPOSIT_AMOUNT, ERROR_INVALID_DEPOSIT_TOKEN,
-        ERROR_INVALID_SHARE_TOKEN_PAYMENT, ERROR_LENDING_POSITION_ALREADY_EXISTS,
-        ERROR_LENDING_POSITION_DOES_NOT_EXIST,
+        ERROR_INVALID_DEPOSIT_AMOUNT, ERROR_INVALID_DEPOSIT_PAYMENTS, ERROR_INVALID_DEPOSIT_TOKEN,
+        ERROR_INVALID_MERGE_PAYMENTS, ERROR_INVALID_REPAYMENT_PAYMENTS,
+        ERROR_INVALID_SHARE_TOKEN_PAYMENT, ERROR_INVALID_SPLIT_AMOUNTS,
+        ERROR_INVALID_SWAPPED_AMOUNT, ERROR_LENDING_POSITION_ALREADY_EXISTS,
+        ERROR_LENDING_POSITION_DOES_NOT_EXIST, ERROR_MERGE_RECIPIENT_NOT_CALLER,
+        ERROR_SWAP_AMOUNT_BELOW_MIN, ERROR_WITHDRAW_AMOUNT_BELOW_MIN,
+    },
+    structs::{
+        AccountPosition, LendingInfo, LendingInfoMultiValue, PositionPnl, ShareTokenAttributes,
+        WithdrawAmounts,
     },
-    structs::{LendingInfo, LendingInfoMultiValue, ShareTokenAttributes},
 };
 use multiversx_sc::imports::*;
 
@@ -34,81 +42,172 @@ use multiversx_sc::imports::*;
 pub trait VaultModule:
     crate::system::pause::PauseModule
     + crate::system::admins::AdminsModule
+    + crate::system::allowlist::AllowlistModule
     + crate::system::storage::StorageModule
     + crate::system::utils::UtilsModule
     + crate::xoxno::liquid_staking::LiquidStakingModule
     + crate::xoxno::lending::LendingModule
     + crate::xoxno::swap_router::SwapRouterModule
     + crate::system::attributes::AttributesModule
+    + crate::system::fees::FeesModule
+    + crate::system::caps::CapsModule
+    + crate::system::holding_period::HoldingPeriodModule
+    + crate::system::health::HealthModule
+    + crate::system::positions::PositionsModule
 {
     // === Endpoints ===
 
     /// Deposits EGLD or xEGLD tokens into the vault.
     ///
     /// When EGLD is deposited, it is first delegated to liquid staking to receive xEGLD.
+    /// Any other ESDT is first swapped to EGLD through the swap router and then delegated.
     /// The xEGLD is then supplied to the lending protocol as collateral.
     /// The user receives share tokens proportional to their contribution.
     ///
     /// ## Share Calculation
-    /// - First deposit: shares = deposit_amount (1:1 ratio)
+    /// - First deposit: shares = deposit_amount - DEAD_SHARES (1:1 ratio). The first deposit
+    ///   must be at least `MIN_FIRST_DEPOSIT_AMOUNT`, and `DEAD_SHARES` are minted to the
+    ///   contract and locked forever, so the share supply never returns to zero
     /// - Subsequent deposits: `shares = (total_shares * deposit_egld_value) / vault_equity`
     ///   where `vault_equity = total_collateral_in_egld - total_debt_in_egld`
+    /// - The management and performance fees are charged before pricing, and the
+    ///   deposit fee is taken from the minted shares
+    ///
+    /// ## Deposit Caps
+    /// The vault equity after the deposit must stay within `max_vault_equity_in_egld`,
+    /// and the cumulative EGLD value credited to the recipient within
+    /// `max_deposit_per_address`. The per-address cap is deposit-only and is not
+    /// released on withdraw. Deposits exceeding a cap are reverted.
+    ///
+    /// ## Arguments
+    /// - `opt_min_shares_out` - Optional minimum amount of shares to mint for this deposit
+    /// - `opt_swap_args` - Swap router arguments for swapping the deposited token to EGLD.
+    ///   Required when depositing a token other than EGLD or xEGLD.
+    /// - `opt_recipient` - Optional address receiving the share token instead of the caller.
+    ///   The allowlist applies to both the caller and the recipient, the per-address deposit
+    ///   cap to the recipient. Arguments are positional, so pass `0` and an empty list for
+    ///   the previous arguments when not needed.
     ///
     /// ## Payment
     /// - EGLD: Will be converted to xEGLD via liquid staking
     /// - xEGLD (supplied_token): Will be directly supplied to lending
+    /// - Any other ESDT: Will be swapped to EGLD, then converted to xEGLD via liquid staking.
+    ///   Shares are computed on the received xEGLD, not on the input token.
+    /// - Optional second payment: an existing share token to be merged with the new position.
+    ///   Only accepted when the caller is the recipient.
     ///
     /// ## Returns
     /// Share token (DynamicMeta NFT) representing the user's share of the vault.
     /// The NFT attributes store the snapshot of total shares and supplied amount
     /// at the time of deposit for PnL calculations.
     ///
+    /// When a share token is sent along with the deposit, it is burned and a single
+    /// merged share token is returned, holding both the existing and the newly minted shares.
+    /// The merged attributes sum the share supply and initial supplied amount and
+    /// keep the latest interaction timestamp.
+    ///
     /// ## Panics
     /// - If the contract is paused
+    /// - If the allowlist is enabled and the caller or the recipient is not allowlisted
     /// - If the deposit amount is zero
-    /// - If the token is not EGLD or the configured supplied_token (xEGLD)
+    /// - If the vault is empty and the deposit is below `MIN_FIRST_DEPOSIT_AMOUNT`
+    /// - If the token is not EGLD or the configured supplied_token (xEGLD) and no swap args are given
+    /// - If the swap returns no EGLD
+    /// - If the second payment is not a valid share token
+    /// - If a share token is sent and the recipient is not the caller
+    /// - If fewer shares than `opt_min_shares_out` are minted
+    /// - If the deposit exceeds the vault equity cap or the recipient's deposit cap
+    /// - If the lending position ends below the minimum health factor
     #[payable]
+    #[allow_multiple_var_args]
     #[endpoint(deposit)]
-    fn deposit(&self) -> EsdtTokenPayment {
-        // TODO: Add optional argument for accepting a share token payment and merging it with the newly created share token
-
+    fn deposit(
+        &self,
+        opt_min_shares_out: OptionalValue<BigUint>,
+        opt_swap_args: OptionalValue<ManagedArgBuffer<Self::Api>>,
+        opt_recipient: OptionalValue<ManagedAddress>,
+    ) -> EsdtTokenPayment {
         self.require_not_paused();
 
-        let payment = self.call_value().egld_or_single_esdt();
+        let caller = self.caller();
+        let recipient = opt_recipient
+            .into_option()
+            .unwrap_or_else(|| caller.clone());
+        self.require_can_deposit(&caller);
+        self.require_can_deposit(&recipient);
+
+        let payments = self.call_value().all_transfers();
+        require!(!payments.is_empty(), ERROR_INVALID_DEPOSIT_AMOUNT);
+        require!(payments.len() <= 2, ERROR_INVALID_DEPOSIT_PAYMENTS);
+
+        let payment = payments.get(0).clone();
         require!(
             self.is_biguint_gt_zero(&payment.amount),
             ERROR_INVALID_DEPOSIT_AMOUNT
         );
 
+        let opt_share_payment = if payments.len() == 2 {
+            let share_payment = payments.get(1).clone().unwrap_esdt();
+            self.require_valid_share_payment(&share_payment);
+            require!(recipient == caller, ERROR_MERGE_RECIPIENT_NOT_CALLER);
+            Some(share_payment)
+        } else {
+            None
+        };
+
         let supplied_token = self.supplied_token().get();
         let liquid_staking_address = self.liquid_staking_address().get();
-        let payment_to_supply = if payment.token_identifier.is_egld() {
-            let delagation_payment =
-                self.execute_delegation(&liquid_staking_address, &payment.amount);
+        let payment_to_supply = if payment.token_identifier == supplied_token {
+            payment.unwrap_esdt()
+        } else {
+            let egld_amount = if payment.token_identifier.is_egld() {
+                payment.amount
+            } else {
+                let swap_args = opt_swap_args.into_option();
+                require!(swap_args.is_some(), ERROR_INVALID_DEPOSIT_TOKEN);
+
+                let esdt_payment = payment.unwrap_esdt();
+                let egld_payment = self.execute_swap(
+                    &self.swap_router_address().get(),
+                    &esdt_payment.token_identifier,
+                    &esdt_payment.amount,
+                    &EgldOrEsdtTokenIdentifier::egld(),
+                    &caller,
+                    swap_args.unwrap(),
+                );
+                require!(
+                    self.is_biguint_gt_zero(&egld_payment.amount),
+                    ERROR_INVALID_SWAPPED_AMOUNT
+                );
+                egld_payment.amount
+            };
+
+            let delagation_payment = self.execute_delegation(&liquid_staking_address, &egld_amount);
             require!(
                 self.is_biguint_gt_zero(&delagation_payment.amount),
                 ERROR_INVALID_DELEGATED_AMOUNT
             );
             delagation_payment
-        } else {
-            require!(
-                payment.token_identifier == supplied_token,
-                ERROR_INVALID_DEPOSIT_TOKEN
-            );
-            payment.unwrap_esdt()
         };
 
+        self.crystallize_fees();
+
         let total_shares = self.total_shares().get();
         let lending_address = self.lending_address().get();
         let lending_position_token = self.lending_position_token().get();
         let lending_position_nonce = self.lending_position_nonce().get();
         let supply_amount_as_md = self.biguint_to_md(payment_to_supply.amount, WAD_PRECISION);
 
-        let deposit_share = if self.is_md_eq_zero(&total_shares) {
+        let (deposit_share, deposit_value_in_egld) = if self.is_md_eq_zero(&total_shares) {
             require!(
                 lending_position_nonce == 0,
                 ERROR_LENDING_POSITION_ALREADY_EXISTS
             );
+            let share_amount = self.calculate_first_deposit_share_amount(&supply_amount_as_md);
+            let deposit_value_in_egld =
+                self.get_ls_value_in_egld(&liquid_staking_address, &supply_amount_as_md);
+
+            self.require_within_vault_cap(&deposit_value_in_egld, &self.md_zero(WAD_PRECISION));
 
             let lending_position_nonce = self.create_supply_position(
                 &lending_address,
@@ -118,24 +217,31 @@ pub trait VaultModule:
             );
 
             self.lending_position_nonce().set(lending_position_nonce);
+            self.lock_dead_shares();
 
-            supply_amount_as_md.clone()
+            (share_amount, deposit_value_in_egld)
         } else {
             require!(
                 lending_position_nonce != 0,
                 ERROR_LENDING_POSITION_DOES_NOT_EXIST
             );
 
-            let share_amount = self.calculate_deposit_share_amount(
+            let total_supplied_in_egld =
+                self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+            let total_borrowed_in_egld =
+                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+
+            let (share_amount, deposit_value_in_egld) = self.price_deposit(
+                &liquid_staking_address,
+                &supply_amount_as_md,
                 total_shares,
-                self.get_ls_value_in_egld(&liquid_staking_address, &supply_amount_as_md),
-                self.get_total_supplied_in_egld(&lending_address, lending_position_nonce),
-                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce),
+                total_supplied_in_egld.clone(),
+                total_borrowed_in_egld.clone(),
             );
 
-            require!(
-                share_amount.gt(&self.md_zero(WAD_PRECISION)),
-                ERROR_INVALID_DEPOSIT_AMOUNT
+            self.require_within_vault_cap(
+                &deposit_value_in_egld,
+                &total_supplied_in_egld.sub(total_borrowed_in_egld),
             );
 
             self.execute_supply(
@@ -146,25 +252,50 @@ pub trait VaultModule:
                 lending_position_nonce,
             );
 
-            share_amount
+            (share_amount, deposit_value_in_egld)
         };
+        self.require_min_health_factor(&lending_address, self.lending_position_nonce().get());
+
+        let deposit_share = self.charge_deposit_fee(deposit_share);
+
+        if let OptionalValue::Some(min_shares_out) = opt_min_shares_out {
+            require!(
+                self.md_to_biguint(&deposit_share) >= min_shares_out,
+                ERROR_DEPOSIT_SHARES_BELOW_MIN
+            );
+        }
+
+        self.track_address_deposit(&recipient, &deposit_value_in_egld);
 
         self.has_collateral().set_if_empty(true);
         self.total_shares().update(|total_shares| {
             *total_shares += &deposit_share;
         });
 
-        self.event_deposit(&supply_amount_as_md, &deposit_share);
+        self.event_deposit(&caller, &recipient, &supply_amount_as_md, &deposit_share);
 
-        self.share_token().nft_create_and_send(
-            &self.caller(),
-            self.md_to_biguint(&deposit_share),
+        let deposit_attributes =
+            ShareTokenAttributes::new(deposit_share, supply_amount_as_md, self.current_ts_millis());
+        let new_attributes = match opt_share_payment {
+            Some(share_payment) => {
+                let existing_attributes = self.burn_share_payment(&share_payment);
+                self.merge_share_attributes(&deposit_attributes, &existing_attributes)
+            }
+            None => deposit_attributes,
+        };
+
+        let new_share_payment = self.share_token().nft_create_and_send(
+            &recipient,
+            self.md_to_biguint(&new_attributes.total_share_supply),
             &self.attributes_to_buffer(
-                deposit_share,
-                supply_amount_as_md,
-                self.current_ts_millis(),
+                new_attributes.total_share_supply,
+                new_attributes.total_initial_supplied_amount,
+                new_attributes.last_interaction_ts_millis,
             ),
-        )
+        );
+        self.register_account_position(&recipient, &new_share_payment);
+
+        new_share_payment
     }
 
     /// Withdraws tokens from the vault by burning share tokens.
@@ -172,7 +303,13 @@ pub trait VaultModule:
     /// Calculates the proportional share of collateral and debt based on
     /// the share tokens provided. If the vault has outstanding debt, the user's
     /// share of debt is first repaid using their collateral share, and then
-    /// the remaining collateral is withdrawn and transferred to the caller.
+    /// the remaining collateral is withdrawn and transferred to the receiver.
+    ///
+    /// Share tokens withdrawn during their holding period are charged the early exit
+    /// penalty, or rejected when no penalty is set.
+    ///
+    /// Several share token nonces can be redeemed at once: their amounts are summed,
+    /// so the debt is repaid and the collateral withdrawn in a single lending round-trip.
     ///
     /// ## Withdrawal Logic
     /// 1. Calculate user's share of total collateral
@@ -182,171 +319,547 @@ pub trait VaultModule:
     ///
     /// # Arguments
     /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral.
+    ///   Required when the vault has outstanding debt. Pass an empty list when
+    ///   only `opt_min_amount_out` or `opt_receiver` is needed.
+    /// - `opt_min_amount_out` - Optional minimum amount of xEGLD to receive
+    /// - `opt_receiver` - Optional address receiving the xEGLD instead of the caller.
+    ///   Pass `0` as `opt_min_amount_out` when no minimum is needed.
+    ///
+    /// # Payment
+    /// One or more share tokens (any nonces) to be burned for withdrawal.
+    ///
+    /// # Transfers
+    /// Sends the withdrawn collateral tokens (xEGLD) directly to the receiver,
+    /// or to the caller if no receiver is given.
+    ///
+    /// # Panics
+    /// - Panics if the contract is paused
+    /// - Panics if the share token payment is invalid
+    /// - Panics if a share token is in its holding period and no early exit penalty is set
+    /// - Panics if the calculated withdrawal amount is zero
+    /// - Panics if collateral is insufficient to cover the debt share
+    /// - Panics if the lending position ends below the minimum health factor
+    /// - Panics if the withdrawn amount is below `opt_min_amount_out`
+    #[payable]
+    #[allow_multiple_var_args]
+    #[endpoint(withdraw)]
+    fn withdraw(
+        &self,
+        swap_steps: OptionalValue<ManagedArgBuffer<Self::Api>>,
+        opt_min_amount_out: OptionalValue<BigUint>,
+        opt_receiver: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_not_paused();
+
+        let receiver = opt_receiver.into_option().unwrap_or_else(|| self.caller());
+        let share_payments = self.call_value().all_esdt_transfers().clone();
+        let amount_to_send = self.process_withdraw(&share_payments, &receiver, swap_steps, None);
+
+        if let OptionalValue::Some(min_amount_out) = opt_min_amount_out {
+            require!(
+                self.md_to_biguint(&amount_to_send) >= min_amount_out,
+                ERROR_WITHDRAW_AMOUNT_BELOW_MIN
+            );
+        }
+
+        if self.is_md_gt_zero(&amount_to_send) {
+            self.tx()
+                .to(&receiver)
+                .single_esdt(
+                    &self.supplied_token().get(),
+                    0,
+                    &self.md_to_biguint(&amount_to_send),
+                )
+                .transfer();
+        }
+    }
+
+    /// Withdraws from the vault and unstakes the withdrawn xEGLD.
+    ///
+    /// Runs the same withdrawal logic as `withdraw`, but instead of sending
+    /// xEGLD, the withdrawn amount is un-delegated through the liquid staking
+    /// contract. Everything returned by the liquid staking contract (the unstake
+    /// NFT, and any EGLD that could be unstaked instantly) is forwarded to the caller.
+    /// The caller claims the EGLD from the liquid staking contract once the
+    /// unbond period has passed.
+    ///
+    /// # Arguments
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral.
     ///   Required when the vault has outstanding debt.
     ///
     /// # Payment
     /// Share token to be burned for withdrawal.
     ///
     /// # Transfers
-    /// Sends the withdrawn collateral tokens (xEGLD) directly to the caller.
+    /// Sends the payments returned by the liquid staking `unDelegate` call to the caller.
     ///
     /// # Panics
     /// - Panics if the contract is paused
     /// - Panics if the share token payment is invalid
+    /// - Panics if a share token is in its holding period and no early exit penalty is set
     /// - Panics if the calculated withdrawal amount is zero
     /// - Panics if collateral is insufficient to cover the debt share
+    /// - Panics if the lending position ends below the minimum health factor
     #[payable]
-    #[endpoint(withdraw)]
-    fn withdraw(&self, swap_steps: OptionalValue<ManagedArgBuffer<Self::Api>>) {
+    #[endpoint(withdrawAndUnstake)]
+    fn withdraw_and_unstake(&self, swap_steps: OptionalValue<ManagedArgBuffer<Self::Api>>) {
         self.require_not_paused();
 
-        let share_token = self.share_token().get_token_id();
-        let share_payment = self.call_value().single_esdt();
-        require!(
-            share_payment.token_identifier == share_token
-                && self.is_biguint_gt_zero(&share_payment.amount),
-            ERROR_INVALID_SHARE_TOKEN_PAYMENT
+        let share_payment = self.call_value().single_esdt().clone();
+        let amount_to_unstake = self.process_withdraw(
+            &ManagedVec::from_single_item(share_payment),
+            &self.caller(),
+            swap_steps,
+            None,
         );
 
-        let total_shares = self.total_shares().get();
-        let share_amount_as_md = self.biguint_to_md(share_payment.amount.clone(), WAD_PRECISION);
+        if self.is_md_gt_zero(&amount_to_unstake) {
+            let unstake_payments = self.execute_un_delegation(
+                &self.liquid_staking_address().get(),
+                &self.supplied_token().get(),
+                &self.md_to_biguint(&amount_to_unstake),
+            );
 
-        let lending_address = self.lending_address().get();
-        let lending_position_nonce = self.lending_position_nonce().get();
-        let lending_position_token = self.lending_position_token().get();
-        let supplied_token = self.supplied_token().get();
-        let borrowed_token = self.borrowed_token().get();
-        let has_debt = self.has_debt().get();
+            self.event_withdraw_unstaked(&amount_to_unstake, unstake_payments.len());
 
-        let total_collateral = self.get_total_supplied(
-            &lending_address,
-            lending_position_nonce,
-            &supplied_token,
-            self.has_collateral().get(),
-        );
+            if !unstake_payments.is_empty() {
+                self.tx()
+                    .to(&self.caller())
+                    .payment(unstake_payments)
+                    .transfer();
+            }
+        }
+    }
 
-        let user_collateral_share = self.calculate_collateral_share_to_withdraw(
-            total_shares.clone(),
-            share_amount_as_md.clone(),
-            total_collateral.clone(),
+    /// Withdraws from the vault and swaps the withdrawn xEGLD to EGLD.
+    ///
+    /// Runs the same withdrawal logic as `withdraw`, then swaps the withdrawn
+    /// xEGLD to EGLD through the swap router and sends native EGLD to the caller.
+    /// Any tokens refunded by the swap router are sent to the caller as well.
+    ///
+    /// # Arguments
+    /// - `min_amount_out` - Minimum amount of EGLD the caller accepts from the swap
+    /// - `swap_args` - Swap router arguments for swapping xEGLD to EGLD
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral.
+    ///   Required when the vault has outstanding debt.
+    ///
+    /// # Payment
+    /// Share token to be burned for withdrawal.
+    ///
+    /// # Transfers
+    /// Sends the swapped EGLD to the caller.
+    ///
+    /// # Panics
+    /// - Panics if the contract is paused
+    /// - Panics if the share token payment is invalid
+    /// - Panics if a share token is in its holding period and no early exit penalty is set
+    /// - Panics if the calculated withdrawal amount is zero
+    /// - Panics if collateral is insufficient to cover the debt share
+    /// - Panics if the lending position ends below the minimum health factor
+    /// - Panics if the swap returns less than `min_amount_out`
+    #[payable]
+    #[endpoint(withdrawAndSwap)]
+    fn withdraw_and_swap(
+        &self,
+        min_amount_out: BigUint,
+        swap_args: ManagedArgBuffer<Self::Api>,
+        swap_steps: OptionalValue<ManagedArgBuffer<Self::Api>>,
+    ) {
+        self.require_not_paused();
+
+        let caller = self.caller();
+        let share_payment = self.call_value().single_esdt().clone();
+        let amount_to_swap = self.process_withdraw(
+            &ManagedVec::from_single_item(share_payment),
+            &caller,
+            swap_steps,
+            None,
         );
 
+        if self.is_md_gt_zero(&amount_to_swap) {
+            let egld_payment = self.execute_swap(
+                &self.swap_router_address().get(),
+                &self.supplied_token().get(),
+                &self.md_to_biguint(&amount_to_swap),
+                &EgldOrEsdtTokenIdentifier::egld(),
+                &caller,
+                swap_args,
+            );
+
+            require!(
+                self.is_biguint_gt_zero(&egld_payment.amount)
+                    && egld_payment.amount >= min_amount_out,
+                ERROR_SWAP_AMOUNT_BELOW_MIN
+            );
+
+            self.event_withdraw_swapped(
+                &amount_to_swap,
+                &self.biguint_to_md(egld_payment.amount.clone(), WAD_PRECISION),
+            );
+
+            self.tx().to(&caller).egld(&egld_payment.amount).transfer();
+        }
+    }
+
+    /// Withdraws an exact net amount of xEGLD from the vault.
+    ///
+    /// Calculates the share tokens needed to receive `amount` after the debt
+    /// repayment and the withdraw fee, redeems only those shares and sends the
+    /// leftover share tokens back to the caller with their updated attributes.
+    /// Any collateral left over by rounding stays in the vault.
+    ///
+    /// # Arguments
+    /// - `amount` - Net amount of supplied token (xEGLD) to receive
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral.
+    ///   Required when the vault has outstanding debt.
+    ///
+    /// # Payment
+    /// Share token covering at least the shares needed for `amount`.
+    ///
+    /// # Transfers
+    /// Sends exactly `amount` xEGLD and the unused share tokens to the caller.
+    ///
+    /// # Panics
+    /// - Panics if the contract is paused
+    /// - Panics if the share token payment is invalid
+    /// - Panics if `amount` is zero
+    /// - Panics if the share token is in its holding period, even with an early exit penalty
+    /// - Panics if the share token payment does not cover the shares needed for `amount`
+    /// - Panics if collateral is insufficient to cover the debt share
+    /// - Panics if the lending position ends below the minimum health factor
+    #[payable]
+    #[endpoint(withdrawExact)]
+    fn withdraw_exact(
+        &self,
+        amount: BigUint,
+        swap_steps: OptionalValue<ManagedArgBuffer<Self::Api>>,
+    ) {
+        self.require_not_paused();
         require!(
-            self.is_md_gt_zero(&user_collateral_share),
+            self.is_biguint_gt_zero(&amount),
             ERROR_INVALID_COLLATERAL_TO_WITHDRAW
         );
 
-        let total_debt_in_egld =
-            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+        let share_payment = self.call_value().single_esdt().clone();
+        self.require_valid_share_payment(&share_payment);
+        self.require_holding_period_elapsed(share_payment.token_nonce);
 
-        let user_debt_share_in_egld = self.calculate_debt_share_to_repay(
-            total_shares.clone(),
-            share_amount_as_md.clone(),
-            total_debt_in_egld.clone(),
+        // Priced on the fee-inclusive supply that `process_withdraw` crystallizes
+        let amount_as_md = self.biguint_to_md(amount, WAD_PRECISION);
+        let share_amount = self.md_to_biguint(&self.calculate_share_amount_for_net_amount(
+            self.get_total_shares_with_pending_fees(),
+            &amount_as_md,
+        ));
+        require!(
+            share_amount <= share_payment.amount,
+            ERROR_INSUFFICIENT_SHARES_FOR_AMOUNT
         );
 
-        let amount_to_send = if self.is_md_gt_zero(&user_debt_share_in_egld) && has_debt {
-            let supplied_token_price_in_egld = self.get_token_price_in_egld(
-                &lending_address,
-                &EgldOrEsdtTokenIdentifier::esdt(supplied_token.clone()),
-            );
-            // let borrowed_token_price_in_egld = self.get_token_price_in_egld(
-            //     &lending_address,
-            //     &EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone()),
-            // );
+        let redeemed_share_payment = EsdtTokenPayment::new(
+            share_payment.token_identifier.clone(),
+            share_payment.token_nonce,
+            share_amount.clone(),
+        );
+        let caller = self.caller();
+        let amount_to_send = self.process_withdraw(
+            &ManagedVec::from_single_item(redeemed_share_payment),
+            &caller,
+            swap_steps,
+            Some(amount_as_md),
+        );
 
-            let collateral_needed_for_repayment = user_debt_share_in_egld
-                .mul(self.wad_as_md())
-                .div(supplied_token_price_in_egld);
+        self.tx()
+            .to(&caller)
+            .single_esdt(
+                &self.supplied_token().get(),
+                0,
+                &self.md_to_biguint(&amount_to_send),
+            )
+            .transfer();
+
+        let leftover_share_amount = &share_payment.amount - &share_amount;
+        if self.is_biguint_gt_zero(&leftover_share_amount) {
+            self.tx()
+                .to(&caller)
+                .single_esdt(
+                    &share_payment.token_identifier,
+                    share_payment.token_nonce,
+                    &leftover_share_amount,
+                )
+                .transfer();
+        }
+    }
 
-            require!(
-                user_collateral_share >= collateral_needed_for_repayment,
-                ERROR_INSUFFICIENT_COLLATERAL_FOR_DEBT
-            );
+    /// Withdraws from the vault by repaying the debt share with borrowed tokens.
+    ///
+    /// Instead of swapping collateral to repay the debt, the caller sends the
+    /// borrowed token covering the debt share of the redeemed shares. The vault
+    /// repays it to the lending position and pays out the full collateral share,
+    /// so no swap slippage is incurred.
+    ///
+    /// The debt share is rounded up, so the remaining holders never carry more
+    /// debt than before. Any borrowed tokens sent above the debt share are refunded.
+    ///
+    /// # Payment
+    /// - One or more share tokens (any nonces) to be burned for withdrawal
+    /// - The borrowed token covering the debt share (not needed without debt)
+    ///
+    /// # Transfers
+    /// Sends the collateral share (xEGLD) and any excess borrowed tokens to the caller.
+    ///
+    /// # Panics
+    /// - Panics if the contract is paused
+    /// - Panics if any other payment is not a valid share token
+    /// - Panics if a share token is in its holding period and no early exit penalty is set
+    /// - Panics if more than one borrowed token payment is received
+    /// - Panics if the calculated withdrawal amount is zero
+    /// - Panics if the borrowed token payment does not cover the debt share
+    /// - Panics if the lending position ends below the minimum health factor
+    #[payable]
+    #[endpoint(withdrawInKind)]
+    fn withdraw_in_kind(&self) {
+        self.require_not_paused();
 
-            let swap_args = swap_steps.into_option().unwrap();
+        let (share_payments, repayment_amount) = self.split_repayment_payment();
+        let share_amount_as_md = self.sum_share_payments(&share_payments);
+        self.crystallize_fees();
+
+        let (total_shares, redeemed_share_amount) =
+            self.charge_redemption_fees(&share_payments, &share_amount_as_md);
+        let (collateral_amount, debt_amount) = self.process_withdraw_in_kind(
+            &share_payments,
+            total_shares,
+            &share_amount_as_md,
+            redeemed_share_amount,
+            repayment_amount,
+        );
 
-            self.execute_repay_with_collateral(
-                &lending_address,
-                lending_position_token.clone(),
-                lending_position_nonce,
-                supplied_token.clone(),
-                &collateral_needed_for_repayment,
-                borrowed_token.clone(),
-                swap_args,
+        self.event_withdraw_in_kind(&collateral_amount, &debt_amount);
+    }
+
+    /// Exits the vault while the contract is paused, without any swap.
+    ///
+    /// The user's pro-rata collateral is withdrawn in one of two ways:
+    /// - If borrowed tokens are sent, they repay the debt share as in `withdrawInKind`
+    ///   and the full collateral share is paid out
+    /// - Otherwise, the debt share stays with the vault and the collateral share is
+    ///   reduced by the debt share valued at the lending oracle price
+    ///
+    /// Either way, the lending position must stay at or above the minimum health factor.
+    ///
+    /// Only the management fee is crystallized, which needs no external call.
+    /// The performance and withdraw fees, and the holding period, do not apply
+    /// to an emergency exit.
+    ///
+    /// # Payment
+    /// - One or more share tokens (any nonces) to be burned for withdrawal
+    /// - Optionally, the borrowed token covering the debt share
+    ///
+    /// # Transfers
+    /// Sends the withdrawn collateral (xEGLD) and any excess borrowed tokens to the caller.
+    ///
+    /// # Panics
+    /// - Panics if the contract is not paused
+    /// - Panics if any other payment is not a valid share token
+    /// - Panics if more than one borrowed token payment is received
+    /// - Panics if the calculated withdrawal amount is zero
+    /// - Panics if the borrowed token payment does not cover the debt share
+    /// - Panics if the collateral share does not cover the debt share
+    /// - Panics if the lending position ends below the minimum health factor
+    #[payable]
+    #[endpoint(emergencyWithdraw)]
+    fn emergency_withdraw(&self) {
+        self.require_paused();
+
+        let (share_payments, repayment_amount) = self.split_repayment_payment();
+        let share_amount_as_md = self.sum_share_payments(&share_payments);
+        self.charge_management_fee();
+
+        if self.is_biguint_gt_zero(&repayment_amount) {
+            let (collateral_amount, debt_amount) = self.process_withdraw_in_kind(
+                &share_payments,
+                self.total_shares().get(),
+                &share_amount_as_md,
+                share_amount_as_md.clone(),
+                repayment_amount,
             );
 
-            user_collateral_share.sub(collateral_needed_for_repayment)
-        } else {
-            user_collateral_share
-        };
+            self.event_emergency_withdraw(&collateral_amount, &debt_amount, true);
+            return;
+        }
 
-        if self.is_md_gt_zero(&amount_to_send) {
+        let withdraw_amounts = self.calculate_withdraw_amounts(
+            self.total_shares().get(),
+            share_amount_as_md.clone(),
+            share_amount_as_md.clone(),
+        );
+
+        let supplied_token = self.supplied_token().get();
+        let net_amount = withdraw_amounts.net_amount;
+        if self.is_md_gt_zero(&net_amount) {
+            let lending_address = self.lending_address().get();
+            let lending_position_nonce = self.lending_position_nonce().get();
             self.execute_withdraw(
                 &lending_address,
                 &supplied_token,
-                &amount_to_send,
-                lending_position_token,
+                &net_amount,
+                self.lending_position_token().get(),
                 lending_position_nonce,
             );
+            self.require_min_health_factor(&lending_address, lending_position_nonce);
+        }
+
+        let caller = self.caller();
+        self.finalize_withdraw(&share_payments, &share_amount_as_md, &caller, &net_amount);
+        self.event_emergency_withdraw(&net_amount, &withdraw_amounts.debt_repaid_in_egld, false);
 
+        if self.is_md_gt_zero(&net_amount) {
             self.tx()
-                .to(&self.caller())
-                .single_esdt(&supplied_token, 0, &self.md_to_biguint(&amount_to_send))
+                .to(&caller)
+                .single_esdt(&supplied_token, 0, &self.md_to_biguint(&net_amount))
                 .transfer();
         }
+    }
 
-        let new_attributes = self
-            .process_new_share_attributes(share_payment.token_nonce, share_amount_as_md.clone());
-
-        if self.is_md_gt_zero(&new_attributes.total_share_supply) {
-            self.share_token().nft_update_attributes(
-                share_payment.token_nonce,
-                &self.attributes_to_buffer(
-                    new_attributes.total_share_supply,
-                    new_attributes.total_initial_supplied_amount,
-                    new_attributes.last_interaction_ts_millis,
-                ),
-            );
-        }
+    /// Merges several share tokens into a single share token.
+    ///
+    /// All received share tokens are burned and one new share token is minted,
+    /// holding the sum of their shares. The lending position is not touched.
+    ///
+    /// # Payment
+    /// Two or more share token payments (any nonces).
+    ///
+    /// # Returns
+    /// The merged share token. Its attributes sum the share supply and initial
+    /// supplied amount of all payments and keep the latest timestamp.
+    ///
+    /// # Panics
+    /// - Panics if fewer than two payments are received
+    /// - Panics if any payment is not a valid share token
+    #[payable]
+    #[endpoint(mergeShares)]
+    fn merge_shares(&self) -> EsdtTokenPayment {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(payments.len() >= 2, ERROR_INVALID_MERGE_PAYMENTS);
 
-        self.share_token()
-            .nft_burn(share_payment.token_nonce, &share_payment.amount);
+        let mut merged_attributes = ShareTokenAttributes::default();
+        for share_payment in payments.iter() {
+            self.require_valid_share_payment(&share_payment);
 
-        if total_shares == share_amount_as_md {
-            self.has_collateral().set(false);
-            self.has_debt().set(false);
-            self.lending_position_nonce().clear();
-        } else {
-            let remaining_collateral =
-                self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
-            if !self.is_md_gt_zero(&remaining_collateral) {
-                self.has_collateral().set(false);
-            }
-            let remaining_debt =
-                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
-            if !self.is_md_gt_zero(&remaining_debt) {
-                self.has_debt().set(false);
-            }
+            let share_attributes = self.burn_share_payment(&share_payment);
+            merged_attributes = self.merge_share_attributes(&merged_attributes, &share_attributes);
         }
-        self.total_shares().update(|total_shares| {
-            *total_shares -= &share_amount_as_md;
-        });
 
-        self.event_withdraw(&amount_to_send, &share_amount_as_md);
-    }
+        self.event_shares_merged(&merged_attributes.total_share_supply, payments.len());
 
-    // === Views ===
+        let caller = self.caller();
+        let merged_share_payment = self.share_token().nft_create_and_send(
+            &caller,
+            self.md_to_biguint(&merged_attributes.total_share_supply),
+            &self.attributes_to_buffer(
+                merged_attributes.total_share_supply,
+                merged_attributes.total_initial_supplied_amount,
+                merged_attributes.last_interaction_ts_millis,
+            ),
+        );
+        self.register_account_position(&caller, &merged_share_payment);
 
-    /// Returns the lending info for the vault's position as a LendingInfo struct.
-    #[view(getLendingInfo)]
-    fn get_lending_info(&self) -> LendingInfo<Self::Api> {
-        let lending_address = self.lending_address().get();
-        let lending_position_nonce = self.lending_position_nonce().get();
-        let supplied_token = self.supplied_token().get();
-        let borrowed_token = self.borrowed_token().get();
+        merged_share_payment
+    }
 
-        LendingInfo::new(
+    /// Splits a share token into several share tokens.
+    ///
+    /// The received share token is burned and one new share token is minted
+    /// for each requested amount. The initial supplied amount is distributed
+    /// proportionally, and every new token keeps the original timestamp.
+    /// The lending position is not touched.
+    ///
+    /// # Arguments
+    /// - `amounts` - Share amounts of the new tokens; must sum up to the payment amount
+    ///
+    /// # Payment
+    /// A single share token.
+    ///
+    /// # Returns
+    /// The newly created share tokens, in the order of `amounts`.
+    ///
+    /// # Panics
+    /// - Panics if the share token payment is invalid
+    /// - Panics if any amount is zero or the amounts do not sum up to the payment amount
+    #[payable]
+    #[endpoint(splitShares)]
+    fn split_shares(
+        &self,
+        amounts: MultiValueEncoded<BigUint>,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        let share_payment = self.call_value().single_esdt().clone();
+        self.require_valid_share_payment(&share_payment);
+
+        let mut total_split_amount = BigUint::zero();
+        for amount in amounts.clone().into_iter() {
+            require!(
+                self.is_biguint_gt_zero(&amount),
+                ERROR_INVALID_SPLIT_AMOUNTS
+            );
+            total_split_amount += amount;
+        }
+        require!(
+            amounts.len() >= 2 && total_split_amount == share_payment.amount,
+            ERROR_INVALID_SPLIT_AMOUNTS
+        );
+
+        let share_attributes = self.burn_share_payment(&share_payment);
+        let mut remaining_initial_supplied_amount =
+            share_attributes.total_initial_supplied_amount.clone();
+        let last_index = amounts.len() - 1;
+
+        let caller = self.caller();
+        let mut new_share_payments = MultiValueEncoded::new();
+        for (index, amount) in amounts.into_iter().enumerate() {
+            let share_amount = self.biguint_to_md(amount.clone(), WAD_PRECISION);
+
+            // The last token takes the remainder so no initial supplied amount is lost to rounding
+            let initial_supplied_amount = if index == last_index {
+                remaining_initial_supplied_amount.clone()
+            } else {
+                share_attributes
+                    .total_initial_supplied_amount
+                    .clone()
+                    .mul(share_amount.clone())
+                    .div(share_attributes.total_share_supply.clone())
+            };
+            remaining_initial_supplied_amount =
+                remaining_initial_supplied_amount.sub(initial_supplied_amount.clone());
+
+            let new_share_payment = self.share_token().nft_create_and_send(
+                &caller,
+                amount,
+                &self.attributes_to_buffer(
+                    share_amount,
+                    initial_supplied_amount,
+                    share_attributes.last_interaction_ts_millis,
+                ),
+            );
+            self.register_account_position(&caller, &new_share_payment);
+            new_share_payments.push(new_share_payment);
+        }
+
+        self.event_shares_split(&share_attributes.total_share_supply, last_index + 1);
+
+        new_share_payments
+    }
+
+    // === Views ===
+
+    /// Returns the lending info for the vault's position as a LendingInfo struct.
+    #[view(getLendingInfo)]
+    fn get_lending_info(&self) -> LendingInfo<Self::Api> {
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let supplied_token = self.supplied_token().get();
+        let borrowed_token = self.borrowed_token().get();
+
+        LendingInfo::new(
             self.get_total_supplied(
                 &lending_address,
                 lending_position_nonce,
@@ -376,94 +889,906 @@ pub trait VaultModule:
         )
     }
 
-    /// Returns the lending info for the vault's position as a MultiValue type.
-    #[view(getLendingInfoMultiValue)]
-    fn get_lending_info_multi_value(&self) -> LendingInfoMultiValue<Self::Api> {
-        self.get_lending_info().into_multi_value()
+    /// Returns the lending info for the vault's position as a MultiValue type.
+    #[view(getLendingInfoMultiValue)]
+    fn get_lending_info_multi_value(&self) -> LendingInfoMultiValue<Self::Api> {
+        self.get_lending_info().into_multi_value()
+    }
+
+    /// Returns the Total Value Locked (TVL) in USD.
+    ///
+    /// TVL = (total_supplied * supplied_token_price_in_usd) - (total_borrowed * borrowed_token_price_in_usd)
+    #[view(getTvl)]
+    fn get_tvl(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let lending_info = self.get_lending_info();
+
+        let total_supplied_value = lending_info
+            .total_supplied
+            .mul(lending_info.supplied_token_price_in_usd);
+        let total_borrowed_value = lending_info
+            .total_borrowed
+            .mul(lending_info.borrowed_token_price_in_usd);
+
+        total_supplied_value.sub(total_borrowed_value)
+    }
+
+    /// Returns the PnL (Profit and Loss) of each share token position held by an address.
+    ///
+    /// The PnL is computed on the vault equity (collateral minus debt) after the
+    /// pending management and performance fees, and reported in both EGLD and USD.
+    /// The initial value is the initially supplied xEGLD at the current xEGLD price,
+    /// so the PnL is measured against simply holding that xEGLD.
+    ///
+    /// # Validation
+    /// - Skips any share tokens that do not have a balance for the given address
+    /// or the address is from another shard.
+    ///
+    /// # Arguments
+    /// - `address` - The address holding the share tokens
+    /// - `nonces` - List of share token nonces to calculate PnL for
+    ///
+    /// # Returns
+    /// A `PositionPnl` breakdown for each held nonce, in the order of `nonces`.
+    #[view(getPositionsPnl)]
+    fn get_positions_pnl(
+        &self,
+        address: ManagedAddress,
+        nonces: MultiValueEncoded<u64>,
+    ) -> MultiValueEncoded<PositionPnl<Self::Api>> {
+        let mut positions_pnl = MultiValueEncoded::new();
+        let share_token_id = self.share_token().get_token_id();
+        let (share_price_in_egld, supplied_token_price_in_egld, egld_price_in_usd) =
+            self.get_pnl_prices();
+
+        for nonce in nonces.into_iter() {
+            let share_balance =
+                self.blockchain()
+                    .get_esdt_balance(&address, &share_token_id, nonce);
+
+            if self.is_biguint_eq_zero(&share_balance) {
+                continue;
+            }
+
+            positions_pnl.push(self.calculate_position_pnl(
+                nonce,
+                self.biguint_to_md(share_balance, WAD_PRECISION),
+                self.get_share_token_attributes_from_address(nonce, &address),
+                &share_price_in_egld,
+                &supplied_token_price_in_egld,
+                &egld_price_in_usd,
+            ));
+        }
+
+        positions_pnl
+    }
+
+    /// Returns the share token positions registered for an address.
+    ///
+    /// Nonces are registered when share tokens are minted or sent to the vault
+    /// (see `syncAccountPositions` for share tokens received through a direct transfer).
+    /// Each position has its attributes and the same PnL breakdown as `getPositionsPnl`.
+    ///
+    /// # Validation
+    /// - Skips any registered share tokens that the address no longer holds
+    /// or the address is from another shard.
+    ///
+    /// # Arguments
+    /// - `address` - The address holding the share tokens
+    ///
+    /// # Returns
+    /// An `AccountPosition` for each held nonce.
+    #[view(getAccountPositions)]
+    fn get_account_positions(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<AccountPosition<Self::Api>> {
+        let mut account_positions = MultiValueEncoded::new();
+        let share_token_id = self.share_token().get_token_id();
+        let (share_price_in_egld, supplied_token_price_in_egld, egld_price_in_usd) =
+            self.get_pnl_prices();
+
+        for nonce in self.account_positions(&address).iter() {
+            let share_balance =
+                self.blockchain()
+                    .get_esdt_balance(&address, &share_token_id, nonce);
+
+            if self.is_biguint_eq_zero(&share_balance) {
+                continue;
+            }
+
+            let share_attributes = self.get_share_token_attributes_from_address(nonce, &address);
+            let position_pnl = self.calculate_position_pnl(
+                nonce,
+                self.biguint_to_md(share_balance, WAD_PRECISION),
+                share_attributes.clone(),
+                &share_price_in_egld,
+                &supplied_token_price_in_egld,
+                &egld_price_in_usd,
+            );
+
+            account_positions.push(AccountPosition::new(share_attributes, position_pnl));
+        }
+
+        account_positions
+    }
+
+    /// Previews the share tokens minted by a deposit.
+    ///
+    /// Runs the same calculations as `deposit`, including the pending management
+    /// and performance fees and the deposit fee, without changing any state.
+    /// EGLD is converted to xEGLD and xEGLD is valued through the same readonly
+    /// liquid staking queries as `deposit`.
+    ///
+    /// # Arguments
+    /// - `token` - EGLD or the supplied token (xEGLD)
+    /// - `amount` - Amount of the token to deposit
+    ///
+    /// # Returns
+    /// The shares the depositor would receive.
+    ///
+    /// # Panics
+    /// - Panics if the amount is zero
+    /// - Panics if the token is not EGLD or the supplied token (xEGLD)
+    /// - Panics if the vault is empty and the deposit is below `MIN_FIRST_DEPOSIT_AMOUNT`
+    /// - Panics if no shares would be minted
+    ///
+    /// The allowlist, the deposit caps and the minimum health factor depend on the
+    /// depositor and the executed deposit, so only `deposit` checks them.
+    #[view(previewDeposit)]
+    fn preview_deposit(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        require!(
+            self.is_biguint_gt_zero(&amount),
+            ERROR_INVALID_DEPOSIT_AMOUNT
+        );
+
+        let liquid_staking_address = self.liquid_staking_address().get();
+        let amount_as_md = self.biguint_to_md(amount, WAD_PRECISION);
+        let supply_amount_as_md = if token == self.supplied_token().get() {
+            amount_as_md
+        } else {
+            require!(token.is_egld(), ERROR_INVALID_DEPOSIT_TOKEN);
+            self.get_egld_value_in_ls(&liquid_staking_address, &amount_as_md)
+        };
+
+        let (total_supplied_in_egld, total_debt_in_egld) = self.get_position_value_in_egld();
+        let total_shares =
+            self.get_total_shares_after_pending_fees(&total_supplied_in_egld, &total_debt_in_egld);
+
+        let share_amount = if self.is_md_eq_zero(&total_shares) {
+            self.calculate_first_deposit_share_amount(&supply_amount_as_md)
+        } else {
+            let (share_amount, _) = self.price_deposit(
+                &liquid_staking_address,
+                &supply_amount_as_md,
+                total_shares,
+                total_supplied_in_egld,
+                total_debt_in_egld,
+            );
+            share_amount
+        };
+
+        share_amount
+            .clone()
+            .sub(self.calculate_deposit_fee_shares(share_amount))
+    }
+
+    /// Previews redeeming an amount of share tokens.
+    ///
+    /// Runs the same calculations as the withdraw endpoints, including the pending
+    /// management and performance fees, the withdraw fee and the early exit penalty,
+    /// without changing any state.
+    ///
+    /// # Arguments
+    /// - `share_amount` - Amount of share tokens to redeem
+    /// - `opt_share_token` - Optional holder address and nonce of the share token redeemed,
+    ///   to apply the early exit penalty while it is in its holding period
+    ///
+    /// # Returns
+    /// The shares redeemed after the fee and the penalty, the collateral withdrawn,
+    /// the debt repaid and the net xEGLD paid out, as a `WithdrawAmounts` struct.
+    ///
+    /// # Panics
+    /// Panics if the share token is in its holding period and no early exit penalty
+    /// is configured.
+    #[view(previewRedeem)]
+    fn preview_redeem(
+        &self,
+        share_amount: BigUint,
+        opt_share_token: OptionalValue<MultiValue2<ManagedAddress, u64>>,
+    ) -> WithdrawAmounts<Self::Api> {
+        let share_amount = self.biguint_to_md(share_amount, WAD_PRECISION);
+        require!(
+            self.is_md_gt_zero(&share_amount),
+            ERROR_INVALID_SHARE_TOKEN_PAYMENT
+        );
+
+        let total_shares = self.get_total_shares_with_pending_fees();
+        let mut redeemed_share_amount = share_amount
+            .clone()
+            .sub(self.calculate_withdraw_fee_shares(share_amount.clone()));
+        if let OptionalValue::Some(share_token) = opt_share_token {
+            let (holder, share_token_nonce) = share_token.into_tuple();
+            let share_attributes =
+                self.get_share_token_attributes_from_address(share_token_nonce, &holder);
+            redeemed_share_amount = redeemed_share_amount.sub(
+                self.calculate_early_exit_penalty_shares(&share_attributes, share_amount.clone()),
+            );
+        }
+
+        self.calculate_withdraw_amounts(total_shares, share_amount, redeemed_share_amount)
+    }
+
+    /// Previews the share tokens needed to withdraw a net amount of xEGLD.
+    ///
+    /// Accounts for the debt repaid with collateral, the pending management and
+    /// performance fees and the withdraw fee. The result is rounded up, so
+    /// redeeming it pays out at least `amount`.
+    ///
+    /// # Arguments
+    /// - `amount` - Net amount of supplied token (xEGLD) to receive
+    ///
+    /// # Returns
+    /// The share amount to send.
+    #[view(previewWithdraw)]
+    fn preview_withdraw(&self, amount: BigUint) -> ManagedDecimal<Self::Api, NumDecimals> {
+        require!(
+            self.is_biguint_gt_zero(&amount),
+            ERROR_INVALID_COLLATERAL_TO_WITHDRAW
+        );
+
+        self.calculate_share_amount_for_net_amount(
+            self.get_total_shares_with_pending_fees(),
+            &self.biguint_to_md(amount, WAD_PRECISION),
+        )
+    }
+
+    // === Private ===
+
+    /// Returns the total share supply after the pending fees, as `crystallize_fees`
+    /// leaves it before a withdrawal. Used by the withdraw previews and `withdrawExact`.
+    ///
+    /// # Panics
+    /// Panics if the vault holds no shares.
+    fn get_total_shares_with_pending_fees(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let (total_supplied_in_egld, total_debt_in_egld) = self.get_position_value_in_egld();
+        let total_shares =
+            self.get_total_shares_after_pending_fees(&total_supplied_in_egld, &total_debt_in_egld);
+
+        require!(
+            self.is_md_gt_zero(&total_shares),
+            ERROR_LENDING_POSITION_DOES_NOT_EXIST
+        );
+
+        total_shares
+    }
+
+    /// Burns the given share token payments and frees the matching collateral.
+    ///
+    /// The management and performance fees are charged first, then the withdraw fee and any
+    /// early exit penalty are taken from the shares, and only the remaining shares are redeemed.
+    /// The share amounts of all payments are summed, so the user's share of the debt
+    /// is repaid with collateral once, then the remaining collateral share is withdrawn
+    /// from the lending position once and kept by the contract. Callers decide how to pay it out.
+    ///
+    /// # Arguments
+    /// - `share_payments` - Share token payments to redeem, of one or more nonces
+    /// - `receiver` - Address the withdrawn amount is paid out to, for the event
+    /// - `swap_steps` - Optional swap route arguments for repaying debt with collateral
+    /// - `opt_exact_amount` - Optional exact amount to withdraw instead of the whole net
+    ///   amount; any surplus stays in the lending position
+    ///
+    /// # Returns
+    /// The amount of supplied token (xEGLD) withdrawn from the lending position.
+    fn process_withdraw(
+        &self,
+        share_payments: &ManagedVec<EsdtTokenPayment>,
+        receiver: &ManagedAddress,
+        swap_steps: OptionalValue<ManagedArgBuffer<Self::Api>>,
+        opt_exact_amount: Option<ManagedDecimal<Self::Api, NumDecimals>>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let share_amount_as_md = self.sum_share_payments(share_payments);
+        self.crystallize_fees();
+
+        let (total_shares, redeemed_share_amount) =
+            self.charge_redemption_fees(share_payments, &share_amount_as_md);
+
+        let withdraw_amounts = self.calculate_withdraw_amounts(
+            total_shares,
+            share_amount_as_md.clone(),
+            redeemed_share_amount,
+        );
+
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let lending_position_token = self.lending_position_token().get();
+        let supplied_token = self.supplied_token().get();
+
+        if self.is_md_gt_zero(&withdraw_amounts.repayment_collateral_amount) {
+            let swap_args = swap_steps.into_option().unwrap();
+
+            self.execute_repay_with_collateral(
+                &lending_address,
+                lending_position_token.clone(),
+                lending_position_nonce,
+                supplied_token.clone(),
+                &withdraw_amounts.repayment_collateral_amount,
+                self.borrowed_token().get(),
+                swap_args,
+            );
+        }
+
+        let amount_to_send = match opt_exact_amount {
+            Some(exact_amount) => {
+                require!(
+                    withdraw_amounts.net_amount >= exact_amount,
+                    ERROR_INSUFFICIENT_SHARES_FOR_AMOUNT
+                );
+                exact_amount
+            }
+            None => withdraw_amounts.net_amount,
+        };
+        if self.is_md_gt_zero(&amount_to_send) {
+            self.execute_withdraw(
+                &lending_address,
+                &supplied_token,
+                &amount_to_send,
+                lending_position_token,
+                lending_position_nonce,
+            );
+        }
+        self.require_min_health_factor(&lending_address, lending_position_nonce);
+
+        self.finalize_withdraw(
+            share_payments,
+            &share_amount_as_md,
+            receiver,
+            &amount_to_send,
+        );
+
+        amount_to_send
+    }
+
+    /// Requires every payment to be a valid share token and returns their total share amount.
+    ///
+    /// # Panics
+    /// - Panics if there are no payments or any payment is not a valid share token
+    fn sum_share_payments(
+        &self,
+        share_payments: &ManagedVec<EsdtTokenPayment>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        require!(
+            !share_payments.is_empty(),
+            ERROR_INVALID_SHARE_TOKEN_PAYMENT
+        );
+
+        let mut share_amount = BigUint::zero();
+        for share_payment in share_payments.iter() {
+            self.require_valid_share_payment(&share_payment);
+            share_amount += &share_payment.amount;
+        }
+
+        self.biguint_to_md(share_amount, WAD_PRECISION)
+    }
+
+    /// Splits the received payments into share token payments and an optional
+    /// borrowed token payment.
+    ///
+    /// # Returns
+    /// `(share_payments, repayment_amount)`, with a zero repayment amount when
+    /// no borrowed token is sent.
+    ///
+    /// # Panics
+    /// - Panics if more than one borrowed token payment is received
+    fn split_repayment_payment(&self) -> (ManagedVec<EsdtTokenPayment>, BigUint) {
+        let borrowed_token = self.borrowed_token().get();
+        let mut share_payments = ManagedVec::new();
+        let mut repayment_amount = BigUint::zero();
+        let mut has_repayment_payment = false;
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            if payment.token_identifier == borrowed_token {
+                require!(!has_repayment_payment, ERROR_INVALID_REPAYMENT_PAYMENTS);
+                has_repayment_payment = true;
+                repayment_amount = payment.amount.clone();
+            } else {
+                share_payments.push(payment.clone());
+            }
+        }
+
+        (share_payments, repayment_amount)
+    }
+
+    /// Charges the withdraw fee and any early exit penalty on share token payments being redeemed.
+    ///
+    /// The total share supply is read before the withdraw fee shares are minted, so
+    /// every withdraw path splits the position over the same supply.
+    ///
+    /// # Arguments
+    /// - `share_payments` - Share token payments being redeemed
+    /// - `share_amount` - Total share amount of the payments
+    ///
+    /// # Returns
+    /// `(total_shares, redeemed_share_amount)` - Share supply before the withdraw fee
+    /// and share amount redeemed after the fee and the penalty.
+    fn charge_redemption_fees(
+        &self,
+        share_payments: &ManagedVec<EsdtTokenPayment>,
+        share_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> (
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let total_shares = self.total_shares().get();
+        let redeemed_share_amount = self
+            .charge_withdraw_fee(share_amount.clone())
+            .sub(self.charge_early_exit_penalty(share_payments));
+
+        (total_shares, redeemed_share_amount)
+    }
+
+    /// Redeems share tokens by repaying their debt share with borrowed tokens.
+    ///
+    /// The debt share is rounded up, so the remaining holders never carry more
+    /// debt than before. The full collateral share and any borrowed tokens sent
+    /// above the debt share are sent to the caller.
+    ///
+    /// The lending position must stay at or above the minimum health factor.
+    ///
+    /// # Arguments
+    /// - `share_payments` - Share token payments to redeem
+    /// - `total_shares` - Total share supply before any withdraw fee
+    /// - `share_amount` - Total share amount of the payments
+    /// - `redeemed_share_amount` - Share amount redeemed after any withdraw fee
+    /// - `repayment_amount` - Amount of borrowed tokens sent by the caller
+    ///
+    /// # Returns
+    /// `(collateral_amount, debt_amount)` - xEGLD sent and borrowed tokens repaid.
+    fn process_withdraw_in_kind(
+        &self,
+        share_payments: &ManagedVec<EsdtTokenPayment>,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        share_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        redeemed_share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+        repayment_amount: BigUint,
+    ) -> (
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let supplied_token = self.supplied_token().get();
+        let borrowed_token = self.borrowed_token().get();
+
+        let collateral_amount = self.calculate_collateral_share_to_withdraw(
+            total_shares.clone(),
+            redeemed_share_amount.clone(),
+            self.get_total_supplied(
+                &lending_address,
+                lending_position_nonce,
+                &supplied_token,
+                self.has_collateral().get(),
+            ),
+        );
+        require!(
+            self.is_md_gt_zero(&collateral_amount),
+            ERROR_INVALID_COLLATERAL_TO_WITHDRAW
+        );
+
+        let total_debt = self.get_total_borrowed(
+            &lending_address,
+            lending_position_nonce,
+            &borrowed_token,
+            self.has_debt().get(),
+        );
+        let debt_amount = self.mul_div_ceil_md(&total_debt, &redeemed_share_amount, &total_shares);
+        let debt_amount_as_biguint = self.md_to_biguint(&debt_amount);
+        require!(
+            repayment_amount >= debt_amount_as_biguint,
+            ERROR_INSUFFICIENT_DEBT_REPAYMENT
+        );
+
+        if self.is_md_gt_zero(&debt_amount) {
+            self.execute_repay(
+                &lending_address,
+                &borrowed_token,
+                lending_position_nonce,
+                &debt_amount,
+            );
+        }
+
+        self.execute_withdraw(
+            &lending_address,
+            &supplied_token,
+            &collateral_amount,
+            self.lending_position_token().get(),
+            lending_position_nonce,
+        );
+        self.require_min_health_factor(&lending_address, lending_position_nonce);
+
+        let caller = self.caller();
+        self.finalize_withdraw(share_payments, share_amount, &caller, &collateral_amount);
+
+        self.tx()
+            .to(&caller)
+            .single_esdt(&supplied_token, 0, &self.md_to_biguint(&collateral_amount))
+            .transfer();
+
+        let refund_amount = repayment_amount - debt_amount_as_biguint;
+        if self.is_biguint_gt_zero(&refund_amount) {
+            self.tx()
+                .to(&caller)
+                .single_esdt(&borrowed_token, 0, &refund_amount)
+                .transfer();
+        }
+
+        (collateral_amount, debt_amount)
+    }
+
+    /// Burns the redeemed share token payments once the collateral is withdrawn,
+    /// then updates the share supply and the position flags.
+    ///
+    /// # Arguments
+    /// - `share_payments` - Redeemed share token payments held by the contract
+    /// - `share_amount` - Total share amount of the payments
+    /// - `receiver` - Address the withdrawn amount is paid out to, for the event
+    /// - `withdrawn_amount` - Amount of supplied token (xEGLD) withdrawn, for the event
+    fn finalize_withdraw(
+        &self,
+        share_payments: &ManagedVec<EsdtTokenPayment>,
+        share_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        receiver: &ManagedAddress,
+        withdrawn_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        for share_payment in share_payments.iter() {
+            self.redeem_share_payment(&share_payment);
+        }
+
+        self.total_shares().update(|total_shares| {
+            *total_shares -= share_amount;
+        });
+
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        if self.is_md_eq_zero(&self.total_shares().get()) {
+            self.has_collateral().set(false);
+            self.has_debt().set(false);
+            self.lending_position_nonce().clear();
+        } else {
+            let remaining_collateral =
+                self.get_total_supplied_in_egld(&lending_address, lending_position_nonce);
+            if !self.is_md_gt_zero(&remaining_collateral) {
+                self.has_collateral().set(false);
+            }
+            let remaining_debt =
+                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+            if !self.is_md_gt_zero(&remaining_debt) {
+                self.has_debt().set(false);
+            }
+        }
+
+        self.event_withdraw(&self.caller(), receiver, withdrawn_amount, share_amount);
+    }
+
+    /// Reads the vault collateral and debt values in EGLD, or zero without a position.
+    ///
+    /// # Returns
+    /// `(total_supplied_in_egld, total_debt_in_egld)`
+    fn get_position_value_in_egld(
+        &self,
+    ) -> (
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let lending_position_nonce = self.lending_position_nonce().get();
+        if lending_position_nonce == 0 {
+            return (self.md_zero(WAD_PRECISION), self.md_zero(WAD_PRECISION));
+        }
+
+        let lending_address = self.lending_address().get();
+        (
+            self.get_total_supplied_in_egld(&lending_address, lending_position_nonce),
+            self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce),
+        )
+    }
+
+    /// Calculates the collateral and debt involved in redeeming shares.
+    ///
+    /// The user's share of the debt is repaid with collateral, so the net
+    /// amount paid out is the collateral share minus the collateral needed
+    /// to repay the debt share.
+    ///
+    /// # Arguments
+    /// - `total_shares` - Current total share supply
+    /// - `share_amount` - Amount of share tokens sent
+    /// - `redeemed_share_amount` - Amount of share tokens redeemed after the withdraw fee
+    ///
+    /// # Panics
+    /// - Panics if the collateral share is zero
+    /// - Panics if the collateral share does not cover the debt share
+    fn calculate_withdraw_amounts(
+        &self,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+        redeemed_share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> WithdrawAmounts<Self::Api> {
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let supplied_token = self.supplied_token().get();
+
+        let total_collateral = self.get_total_supplied(
+            &lending_address,
+            lending_position_nonce,
+            &supplied_token,
+            self.has_collateral().get(),
+        );
+
+        let collateral_amount = self.calculate_collateral_share_to_withdraw(
+            total_shares.clone(),
+            redeemed_share_amount.clone(),
+            total_collateral,
+        );
+
+        require!(
+            self.is_md_gt_zero(&collateral_amount),
+            ERROR_INVALID_COLLATERAL_TO_WITHDRAW
+        );
+
+        let debt_repaid_in_egld = if self.has_debt().get() {
+            self.calculate_debt_share_to_repay(
+                total_shares,
+                redeemed_share_amount.clone(),
+                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce),
+            )
+        } else {
+            self.md_zero(WAD_PRECISION)
+        };
+
+        let repayment_collateral_amount = if self.is_md_gt_zero(&debt_repaid_in_egld) {
+            let supplied_token_price_in_egld = self.get_token_price_in_egld(
+                &lending_address,
+                &EgldOrEsdtTokenIdentifier::esdt(supplied_token),
+            );
+
+            debt_repaid_in_egld
+                .clone()
+                .mul(self.wad_as_md())
+                .div(supplied_token_price_in_egld)
+        } else {
+            self.md_zero(WAD_PRECISION)
+        };
+
+        require!(
+            collateral_amount >= repayment_collateral_amount,
+            ERROR_INSUFFICIENT_COLLATERAL_FOR_DEBT
+        );
+
+        let net_amount = collateral_amount
+            .clone()
+            .sub(repayment_collateral_amount.clone());
+
+        WithdrawAmounts::new(
+            share_amount,
+            redeemed_share_amount,
+            collateral_amount,
+            debt_repaid_in_egld,
+            repayment_collateral_amount,
+            net_amount,
+        )
     }
 
-    /// Returns the Total Value Locked (TVL) in USD.
+    /// Reads the vault collateral and debt, then calculates the share tokens
+    /// to send to receive at least a net amount of collateral.
     ///
-    /// TVL = (total_supplied * supplied_token_price_in_usd) - (total_borrowed * borrowed_token_price_in_usd)
-    #[view(getTvl)]
-    fn get_tvl(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
-        let lending_info = self.get_lending_info();
+    /// # Arguments
+    /// - `total_shares` - Current total share supply
+    /// - `net_amount` - Net amount of supplied token (xEGLD) to receive
+    fn calculate_share_amount_for_net_amount(
+        &self,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        net_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let lending_address = self.lending_address().get();
+        let lending_position_nonce = self.lending_position_nonce().get();
+        let supplied_token = self.supplied_token().get();
 
-        let total_supplied_value = lending_info
-            .total_supplied
-            .mul(lending_info.supplied_token_price_in_usd);
-        let total_borrowed_value = lending_info
-            .total_borrowed
-            .mul(lending_info.borrowed_token_price_in_usd);
+        let total_collateral = self.get_total_supplied(
+            &lending_address,
+            lending_position_nonce,
+            &supplied_token,
+            self.has_collateral().get(),
+        );
 
-        total_supplied_value.sub(total_borrowed_value)
+        let total_debt_in_collateral = if self.has_debt().get() {
+            let total_debt_in_egld =
+                self.get_total_borrowed_in_egld(&lending_address, lending_position_nonce);
+            let supplied_token_price_in_egld = self.get_token_price_in_egld(
+                &lending_address,
+                &EgldOrEsdtTokenIdentifier::esdt(supplied_token),
+            );
+
+            total_debt_in_egld
+                .mul(self.wad_as_md())
+                .div(supplied_token_price_in_egld)
+        } else {
+            self.md_zero(WAD_PRECISION)
+        };
+
+        self.calculate_share_amount_to_redeem(
+            total_shares,
+            net_amount,
+            total_collateral,
+            total_debt_in_collateral,
+        )
     }
 
-    /// Returns the PnL (Profit and Loss) for a list of share token positions.
+    /// Calculates the share tokens to send to receive at least a net amount of collateral.
     ///
-    /// Given an address and a list of share token nonces, calculates:
-    /// - For each token: share_initial_value and share_current_value
-    /// - Returns tuple of (pnl, pnl_percentage) where:
-    ///   - pnl = total_share_current_value - total_share_initial_value
-    ///   - pnl_percentage = pnl * 100 / total_share_initial_value
+    /// Inverts `calculate_withdraw_amounts` and the withdraw fee:
+    /// - redeemed_shares = net_amount * total_shares / (total_collateral - total_debt_in_collateral)
+    /// - shares = redeemed_shares * BPS / (BPS - withdraw_fee_bps)
     ///
-    /// # Validation
-    /// - Skips any share tokens that do not have a balance for the given address
-    /// or the address is from another shard.
+    /// Both divisions are rounded up, and two units of the net amount are added
+    /// to cover the rounding down of the collateral and debt shares on redemption.
     ///
     /// # Arguments
-    /// - `address` - The address holding the share tokens
-    /// - `nonces` - List of share token nonces to calculate PnL for
+    /// - `total_shares` - Current total share supply
+    /// - `net_amount` - Net amount of supplied token (xEGLD) to receive
+    /// - `total_collateral` - Total collateral (xEGLD)
+    /// - `total_debt_in_collateral` - Total debt valued in collateral (xEGLD)
     ///
-    /// # Returns
-    /// The total PnL as a ManagedDecimal.
-    #[view(getPositionsPnl)]
-    fn get_positions_pnl(
+    /// # Panics
+    /// - Panics if the vault collateral does not cover its debt
+    fn calculate_share_amount_to_redeem(
         &self,
-        address: ManagedAddress,
-        nonces: MultiValueEncoded<u64>,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        net_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        total_collateral: ManagedDecimal<Self::Api, NumDecimals>,
+        total_debt_in_collateral: ManagedDecimal<Self::Api, NumDecimals>,
     ) -> ManagedDecimal<Self::Api, NumDecimals> {
-        let share_token_id = self.share_token().get_token_id();
-        let total_shares = self.total_shares().get();
-        let lending_info = self.get_lending_info();
+        require!(
+            total_collateral > total_debt_in_collateral,
+            ERROR_INVALID_COLLATERAL_TO_DEBT_RATIO
+        );
 
-        let mut total_share_initial_value = self.md_zero(WAD_PRECISION);
-        let mut total_share_current_value = self.md_zero(WAD_PRECISION);
+        let rounding_margin = self.biguint_to_md(BigUint::from(2u32), WAD_PRECISION);
+        let redeemed_share_amount = self.mul_div_ceil_md(
+            &net_amount.clone().add(rounding_margin),
+            &total_shares,
+            &total_collateral.sub(total_debt_in_collateral),
+        );
 
-        for nonce in nonces.into_iter() {
-            let share_balance =
-                self.blockchain()
-                    .get_esdt_balance(&address, &share_token_id, nonce);
+        let withdraw_fee_bps = self.withdraw_fee_bps().get();
+        self.mul_div_ceil_md(
+            &redeemed_share_amount,
+            &self.biguint_to_md(BigUint::from(BPS), WAD_PRECISION),
+            &self.biguint_to_md(BigUint::from(BPS as u64 - withdraw_fee_bps), WAD_PRECISION),
+        )
+    }
 
-            if self.is_biguint_eq_zero(&share_balance) {
-                continue;
-            }
+    /// Reads the prices used for the PnL of share token positions.
+    ///
+    /// The share price is computed on the vault equity after the pending
+    /// management and performance fees, or zero without equity.
+    ///
+    /// # Returns
+    /// `(share_price_in_egld, supplied_token_price_in_egld, egld_price_in_usd)`
+    fn get_pnl_prices(
+        &self,
+    ) -> (
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let (total_supplied_in_egld, total_debt_in_egld) = self.get_position_value_in_egld();
+        let total_shares =
+            self.get_total_shares_after_pending_fees(&total_supplied_in_egld, &total_debt_in_egld);
+        let share_price_in_egld =
+            if self.is_md_gt_zero(&total_shares) && total_supplied_in_egld > total_debt_in_egld {
+                self.calculate_share_price(
+                    &total_supplied_in_egld.sub(total_debt_in_egld),
+                    &total_shares,
+                )
+            } else {
+                self.md_zero(WAD_PRECISION)
+            };
 
-            let share_amount = self.biguint_to_md(share_balance, WAD_PRECISION);
-            let share_attributes = self.get_share_token_attributes_from_address(nonce, &address);
+        let lending_address = self.lending_address().get();
+        let supplied_token_price_in_egld = self.get_token_price_in_egld(
+            &lending_address,
+            &EgldOrEsdtTokenIdentifier::esdt(self.supplied_token().get()),
+        );
+        let egld_price_in_usd =
+            self.get_token_price_in_usd(&lending_address, &EgldOrEsdtTokenIdentifier::egld());
+
+        (
+            share_price_in_egld,
+            supplied_token_price_in_egld,
+            egld_price_in_usd,
+        )
+    }
 
-            let share_initial_value = share_amount
+    /// Calculates the PnL of a share token position.
+    ///
+    /// Uses the formulas:
+    /// - initial_value = share_amount * total_initial_supplied_amount / total_share_supply * supplied_token_price_in_egld
+    /// - current_value = share_amount * share_price_in_egld
+    /// - pnl = current_value - initial_value
+    /// - pnl_percentage = current_value * 100 / initial_value - 100
+    ///
+    /// USD values are the EGLD values multiplied by the EGLD price in USD.
+    ///
+    /// # Arguments
+    /// - `nonce` - Share token nonce
+    /// - `share_amount` - Amount of share tokens held
+    /// - `share_attributes` - Attributes of the share token
+    /// - `share_price_in_egld` - Current share price in EGLD
+    /// - `supplied_token_price_in_egld` - Supplied token (xEGLD) price in EGLD
+    /// - `egld_price_in_usd` - EGLD price in USD
+    fn calculate_position_pnl(
+        &self,
+        nonce: u64,
+        share_amount: ManagedDecimal<Self::Api, NumDecimals>,
+        share_attributes: ShareTokenAttributes<Self::Api>,
+        share_price_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        supplied_token_price_in_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        egld_price_in_usd: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> PositionPnl<Self::Api> {
+        let initial_value_in_egld = if self.is_md_gt_zero(&share_attributes.total_share_supply) {
+            share_amount
                 .clone()
                 .mul(share_attributes.total_initial_supplied_amount)
-                .mul(lending_info.supplied_token_price_in_usd.clone())
-                .div(share_attributes.total_share_supply);
+                .div(share_attributes.total_share_supply)
+                .mul(supplied_token_price_in_egld.clone())
+                .div(self.wad_as_md())
+        } else {
+            self.md_zero(WAD_PRECISION)
+        };
+        let current_value_in_egld = share_amount
+            .clone()
+            .mul(share_price_in_egld.clone())
+            .div(self.wad_as_md());
 
-            let share_current_value = share_amount
-                .mul(lending_info.total_supplied.clone())
-                .mul(lending_info.supplied_token_price_in_usd.clone())
-                .div(total_shares.clone());
+        let initial_value_in_usd = initial_value_in_egld
+            .clone()
+            .mul(egld_price_in_usd.clone())
+            .div(self.wad_as_md());
+        let current_value_in_usd = current_value_in_egld
+            .clone()
+            .mul(egld_price_in_usd.clone())
+            .div(self.wad_as_md());
 
-            total_share_initial_value = total_share_initial_value.add(share_initial_value);
-            total_share_current_value = total_share_current_value.add(share_current_value);
-        }
+        let pnl_percentage = if self.is_md_gt_zero(&initial_value_in_egld) {
+            let hundred = self.biguint_to_md(BigUint::from(100 * WAD), WAD_PRECISION);
+            current_value_in_egld
+                .clone()
+                .mul(hundred.clone())
+                .div(initial_value_in_egld.clone())
+                .into_signed()
+                .sub(hundred.into_signed())
+        } else {
+            self.md_zero(WAD_PRECISION).into_signed()
+        };
 
-        total_share_current_value
-            .clone()
-            .sub(total_share_initial_value.clone())
+        PositionPnl::new(
+            nonce,
+            share_amount,
+            initial_value_in_egld.clone(),
+            current_value_in_egld.clone(),
+            current_value_in_egld
+                .into_signed()
+                .sub(initial_value_in_egld.into_signed()),
+            initial_value_in_usd.clone(),
+            current_value_in_usd.clone(),
+            current_value_in_usd
+                .into_signed()
+                .sub(initial_value_in_usd.into_signed()),
+            pnl_percentage,
+        )
     }
 
-    // === Private ===
-
     /// Calculates the share tokens to mint for a deposit.
     ///
     /// Uses the formula: shares = (total_shares * deposit) / (total_supply - total_debt)
@@ -489,6 +1814,98 @@ pub trait VaultModule:
             .div(total_current_supply_in_egld.sub(total_current_debt_in_egld))
     }
 
+    /// Calculates the share tokens to mint for the first deposit into an empty vault.
+    ///
+    /// Uses the formula: shares = deposit - DEAD_SHARES
+    ///
+    /// A large minimum first deposit together with the locked dead shares makes
+    /// a donation to the vault too expensive to round later deposits down.
+    ///
+    /// # Arguments
+    /// - `supply_amount` - Amount of supplied token (xEGLD) being deposited
+    ///
+    /// # Panics
+    /// - Panics if the amount is below `MIN_FIRST_DEPOSIT_AMOUNT`
+    fn calculate_first_deposit_share_amount(
+        &self,
+        supply_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        require!(
+            self.md_to_biguint(supply_amount) >= BigUint::from(MIN_FIRST_DEPOSIT_AMOUNT),
+            ERROR_FIRST_DEPOSIT_TOO_SMALL
+        );
+
+        supply_amount
+            .clone()
+            .sub(self.biguint_to_md(BigUint::from(DEAD_SHARES), WAD_PRECISION))
+    }
+
+    /// Prices a deposit of supplied tokens into a vault holding shares.
+    ///
+    /// Values the supplied tokens through liquid staking and mints shares pro-rata to
+    /// the vault equity. Shared by `deposit` and `previewDeposit`, so the two never drift apart.
+    ///
+    /// # Arguments
+    /// - `liquid_staking_address` - Address of the liquid staking contract
+    /// - `supply_amount` - Amount of supplied token (xEGLD) deposited
+    /// - `total_shares` - Current total share supply
+    /// - `total_supplied_in_egld` - Total collateral value in EGLD
+    /// - `total_borrowed_in_egld` - Total debt value in EGLD
+    ///
+    /// # Returns
+    /// `(share_amount, deposit_value_in_egld)` - Shares minted before the deposit fee
+    /// and value of the deposit in EGLD.
+    ///
+    /// # Panics
+    /// Panics with `ERROR_INVALID_DEPOSIT_AMOUNT` if no shares would be minted.
+    fn price_deposit(
+        &self,
+        liquid_staking_address: &ManagedAddress,
+        supply_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        total_shares: ManagedDecimal<Self::Api, NumDecimals>,
+        total_supplied_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+        total_borrowed_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> (
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let deposit_value_in_egld =
+            self.get_ls_value_in_egld(liquid_staking_address, supply_amount);
+        let share_amount = self.calculate_deposit_share_amount(
+            total_shares,
+            deposit_value_in_egld.clone(),
+            total_supplied_in_egld,
+            total_borrowed_in_egld,
+        );
+
+        require!(
+            share_amount.gt(&self.md_zero(WAD_PRECISION)),
+            ERROR_INVALID_DEPOSIT_AMOUNT
+        );
+
+        (share_amount, deposit_value_in_egld)
+    }
+
+    /// Mints `DEAD_SHARES` to the contract itself and adds them to the total shares.
+    ///
+    /// No endpoint can move these shares out of the contract, so they stay locked forever.
+    fn lock_dead_shares(&self) {
+        let dead_shares = self.biguint_to_md(BigUint::from(DEAD_SHARES), WAD_PRECISION);
+
+        self.share_token().nft_create(
+            self.md_to_biguint(&dead_shares),
+            &self.attributes_to_buffer(
+                dead_shares.clone(),
+                self.md_zero(WAD_PRECISION),
+                self.current_ts_millis(),
+            ),
+        );
+
+        self.total_shares().update(|total_shares| {
+            *total_shares += &dead_shares;
+        });
+    }
+
     /// Calculates the collateral amount to withdraw for a given share amount.
     ///
     /// Uses the formula: collateral = (total_collateral * share_amount) / total_shares
@@ -523,8 +1940,43 @@ pub trait VaultModule:
         (total_debt.mul(share_amount)).div(total_shares)
     }
 
+    /// Burns a redeemed share token payment and updates the attributes of its nonce.
+    ///
+    /// The caller's positions registry is updated with the remaining share supply.
+    ///
+    /// # Arguments
+    /// - `share_payment` - Redeemed share token payment held by the contract
+    fn redeem_share_payment(&self, share_payment: &EsdtTokenPayment) {
+        let share_amount = self.biguint_to_md(share_payment.amount.clone(), WAD_PRECISION);
+        let new_attributes =
+            self.process_new_share_attributes(share_payment.token_nonce, share_amount);
+
+        self.update_account_position(
+            &self.caller(),
+            share_payment.token_nonce,
+            &new_attributes.total_share_supply,
+        );
+
+        if self.is_md_gt_zero(&new_attributes.total_share_supply) {
+            self.share_token().nft_update_attributes(
+                share_payment.token_nonce,
+                &self.attributes_to_buffer(
+                    new_attributes.total_share_supply,
+                    new_attributes.total_initial_supplied_amount,
+                    new_attributes.last_interaction_ts_millis,
+                ),
+            );
+        }
+
+        self.share_token()
+            .nft_burn(share_payment.token_nonce, &share_payment.amount);
+    }
+
     /// Updates the share token attributes after a withdrawal.
     ///
+    /// The last interaction timestamp is kept, so a partial withdraw does not
+    /// restart the holding period of the shares left on the nonce.
+    ///
     /// # Arguments
     /// - `share_attributes` - Current attributes of the share token
     /// - `share_withdraw_amount` - Amount of share tokens being burned
@@ -551,38 +2003,190 @@ pub trait VaultModule:
             .clone()
             .sub(withdrawn_part_of_initial_supplied_amount);
 
+        let last_interaction_ts_millis = share_attributes.last_interaction_ts_millis;
         share_attributes.update(
             new_total_share_supply,
             new_total_initial_supplied_amount,
-            self.current_ts_millis(),
+            last_interaction_ts_millis,
         );
 
         share_attributes
     }
 
+    /// Requires the payment to be a non-zero amount of the vault's share token.
+    fn require_valid_share_payment(&self, share_payment: &EsdtTokenPayment) {
+        require!(
+            share_payment.token_identifier == self.share_token().get_token_id()
+                && self.is_biguint_gt_zero(&share_payment.amount),
+            ERROR_INVALID_SHARE_TOKEN_PAYMENT
+        );
+    }
+
+    /// Burns a share token payment received by the contract and returns
+    /// the attributes carried by the burned amount.
+    ///
+    /// If the nonce still has supply left (e.g. only part of a balance was sent),
+    /// its attributes are reduced proportionally, keeping the original timestamp.
+    /// The caller's positions registry is updated.
+    ///
+    /// # Arguments
+    /// - `share_payment` - Share token payment held by the contract
+    ///
+    /// # Returns
+    /// Attributes of the burned part: share amount, its part of the initial
+    /// supplied amount and the last interaction timestamp of the nonce.
+    fn burn_share_payment(
+        &self,
+        share_payment: &EsdtTokenPayment,
+    ) -> ShareTokenAttributes<Self::Api> {
+        let share_amount = self.biguint_to_md(share_payment.amount.clone(), WAD_PRECISION);
+        let share_attributes = self.get_share_token_attributes(share_payment.token_nonce);
+
+        let burned_initial_supplied_amount = share_attributes
+            .total_initial_supplied_amount
+            .clone()
+            .mul(share_amount.clone())
+            .div(share_attributes.total_share_supply.clone());
+
+        let remaining_share_supply = share_attributes
+            .total_share_supply
+            .clone()
+            .sub(share_amount.clone());
+        self.update_account_position(
+            &self.caller(),
+            share_payment.token_nonce,
+            &remaining_share_supply,
+        );
+
+        if self.is_md_gt_zero(&remaining_share_supply) {
+            self.share_token().nft_update_attributes(
+                share_payment.token_nonce,
+                &self.attributes_to_buffer(
+                    remaining_share_supply,
+                    share_attributes
+                        .total_initial_supplied_amount
+                        .clone()
+                        .sub(burned_initial_supplied_amount.clone()),
+                    share_attributes.last_interaction_ts_millis,
+                ),
+            );
+        }
+
+        self.share_token()
+            .nft_burn(share_payment.token_nonce, &share_payment.amount);
+
+        ShareTokenAttributes::new(
+            share_amount,
+            burned_initial_supplied_amount,
+            share_attributes.last_interaction_ts_millis,
+        )
+    }
+
     // === Events ===
 
     /// Emitted when a deposit is made.
     ///
     /// # Indexed Parameters
+    /// - `caller` - Address that made the deposit
+    /// - `recipient` - Address that received the share token
     /// - `supplied_amount` - Amount of tokens supplied
     /// - `share_returned` - Amount of share tokens minted
     #[event("deposit")]
     fn event_deposit(
         &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
         #[indexed] supplied_amount: &ManagedDecimal<Self::Api, NumDecimals>,
         #[indexed] share_returned: &ManagedDecimal<Self::Api, NumDecimals>,
     );
 
+    /// Emitted when share tokens are merged.
+    ///
+    /// # Indexed Parameters
+    /// - `share_amount` - Amount of shares in the merged token
+    /// - `merged_tokens` - Number of share tokens merged
+    #[event("sharesMerged")]
+    fn event_shares_merged(
+        &self,
+        #[indexed] share_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] merged_tokens: usize,
+    );
+
+    /// Emitted when a share token is split.
+    ///
+    /// # Indexed Parameters
+    /// - `share_amount` - Amount of shares in the split token
+    /// - `new_tokens` - Number of share tokens created
+    #[event("sharesSplit")]
+    fn event_shares_split(
+        &self,
+        #[indexed] share_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] new_tokens: usize,
+    );
+
     /// Emitted when a withdrawal is made.
     ///
     /// # Indexed Parameters
+    /// - `caller` - Address that sent the share token
+    /// - `receiver` - Address that received the withdrawn amount
     /// - `withdrawn_amount` - Amount of tokens withdrawn
     /// - `share_burned` - Amount of share tokens burned
     #[event("withdraw")]
     fn event_withdraw(
         &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] receiver: &ManagedAddress,
         #[indexed] withdrawn_amount: &ManagedDecimal<Self::Api, NumDecimals>,
         #[indexed] share_burned: &ManagedDecimal<Self::Api, NumDecimals>,
     );
+
+    /// Emitted when a withdrawal is unstaked through liquid staking.
+    ///
+    /// # Indexed Parameters
+    /// - `unstaked_amount` - Amount of supplied tokens (xEGLD) un-delegated
+    /// - `returned_payments` - Number of payments returned by the liquid staking contract
+    #[event("withdrawUnstaked")]
+    fn event_withdraw_unstaked(
+        &self,
+        #[indexed] unstaked_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] returned_payments: usize,
+    );
+
+    /// Emitted when a withdrawal repays its debt share with borrowed tokens.
+    ///
+    /// # Indexed Parameters
+    /// - `withdrawn_amount` - Amount of supplied tokens (xEGLD) sent to the user
+    /// - `repaid_amount` - Amount of borrowed tokens repaid
+    #[event("withdrawInKind")]
+    fn event_withdraw_in_kind(
+        &self,
+        #[indexed] withdrawn_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] repaid_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
+    /// Emitted when an emergency withdrawal is made while the contract is paused.
+    ///
+    /// # Indexed Parameters
+    /// - `withdrawn_amount` - Amount of supplied tokens (xEGLD) sent to the user
+    /// - `debt_amount` - Borrowed tokens repaid, or the debt share left with the vault in EGLD
+    /// - `repaid_in_kind` - Whether the debt share was repaid with borrowed tokens
+    #[event("emergencyWithdraw")]
+    fn event_emergency_withdraw(
+        &self,
+        #[indexed] withdrawn_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] debt_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] repaid_in_kind: bool,
+    );
+
+    /// Emitted when a withdrawal is swapped to EGLD through the swap router.
+    ///
+    /// # Indexed Parameters
+    /// - `swapped_amount` - Amount of supplied tokens (xEGLD) swapped
+    /// - `received_amount` - Amount of EGLD received and sent to the user
+    #[event("withdrawSwapped")]
+    fn event_withdraw_swapped(
+        &self,
+        #[indexed] swapped_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] received_amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
 }
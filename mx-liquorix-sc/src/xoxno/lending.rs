@@ -3,7 +3,7 @@
 //! Provides integration with the XOXNO lending protocol.
 //!
 //! Contains functions for supplying collateral, borrowing, withdrawing,
-//! and querying position information.
+//! flash loans and querying position information.
 
 use crate::{
     constants::WAD_PRECISION,
@@ -20,6 +20,7 @@ use multiversx_sc::imports::*;
 /// Provides functionality to:
 /// - Create and manage supply positions
 /// - Execute borrows and repayments
+/// - Execute flash loans
 /// - Query collateral and debt amounts
 /// - Get token prices
 #[multiversx_sc::module]
@@ -342,6 +343,39 @@ pub trait LendingModule: crate::system::utils::UtilsModule {
         );
     }
 
+    /// Executes a flash loan of the borrowed token from the lending protocol.
+    ///
+    /// The lending protocol sends the loan to `endpoint` on this contract, appending
+    /// the contract's own address to `arguments`. The endpoint must send back the
+    /// loan plus the flash loan fee before returning.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `borrowed_token` - Token to flash-borrow
+    /// - `amount` - Amount to flash-borrow
+    /// - `endpoint` - Endpoint of this contract receiving the loan
+    /// - `arguments` - Arguments passed to the receiving endpoint
+    fn execute_flash_loan(
+        &self,
+        lending_address: &ManagedAddress,
+        borrowed_token: &EsdtTokenIdentifier,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        endpoint: ManagedBuffer,
+        arguments: ManagedArgBuffer<Self::Api>,
+    ) {
+        self.tx()
+            .to(lending_address)
+            .typed(xoxno_lending_controller_proxy::ControllerProxy)
+            .flash_loan(
+                EgldOrEsdtTokenIdentifier::esdt(borrowed_token.clone()),
+                self.md_to_biguint(amount),
+                self.sc(),
+                endpoint,
+                arguments,
+            )
+            .sync_call();
+    }
+
     /// Gets the total supplied (collateral) amount of a specific token.
     ///
     /// Queries the lending protocol for the current collateral balance.
@@ -494,6 +528,50 @@ pub trait LendingModule: crate::system::utils::UtilsModule {
             .sync_call_readonly()
     }
 
+    /// Gets the flash loan fee of a token from the lending protocol's asset config.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `token` - Token identifier (EGLD or ESDT)
+    ///
+    /// # Returns
+    /// Flash loan fee in BPS as ManagedDecimal.
+    fn get_flash_loan_fee_bps(
+        &self,
+        lending_address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        self.tx()
+            .to(lending_address)
+            .typed(xoxno_lending_controller_proxy::ControllerProxy)
+            .asset_config(token)
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .flashloan_fee_bps
+    }
+
+    /// Gets the number of decimals of a token from the lending protocol's oracle.
+    ///
+    /// # Arguments
+    /// - `lending_address` - Address of the lending controller
+    /// - `token` - Token identifier (EGLD or ESDT)
+    ///
+    /// # Returns
+    /// Number of decimals of the token.
+    fn get_token_decimals(
+        &self,
+        lending_address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> usize {
+        self.tx()
+            .to(lending_address)
+            .typed(xoxno_lending_controller_proxy::ControllerProxy)
+            .token_oracle(token)
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .asset_decimals
+    }
+
     /// Checks if the vault's lending position can be liquidated.
     ///
     /// A position becomes liquidatable when the health factor falls below 1,
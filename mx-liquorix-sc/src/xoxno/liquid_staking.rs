@@ -2,7 +2,8 @@
 //!
 //! Provides integration with the XOXNO liquid staking protocol.
 //!
-//! Enables conversion of EGLD to xEGLD through delegation.
+//! Enables conversion of EGLD to xEGLD through delegation, and back
+//! through un-delegation.
 
 use crate::{
     constants::WAD_PRECISION, errors::ERROR_DELEGATE_FAILED, proxies::xoxno_liquid_staking_proxy,
@@ -11,7 +12,8 @@ use multiversx_sc::imports::*;
 
 /// Module for XOXNO liquid staking protocol interactions.
 ///
-/// Provides functionality to delegate EGLD and receive xEGLD tokens.
+/// Provides functionality to delegate EGLD and receive xEGLD tokens,
+/// and to un-delegate xEGLD.
 #[multiversx_sc::module]
 pub trait LiquidStakingModule: crate::system::utils::UtilsModule {
     // === Private ===
@@ -57,10 +59,41 @@ pub trait LiquidStakingModule: crate::system::utils::UtilsModule {
         }
     }
 
+    /// Executes un-delegation of xEGLD through the liquid staking protocol.
+    ///
+    /// Sends xEGLD to the liquid staking contract, which burns it and returns
+    /// an unstake NFT redeemable for EGLD after the unbond period.
+    ///
+    /// # Arguments
+    /// - `liquid_staking_address` - Address of the liquid staking contract
+    /// - `supplied_token` - The xEGLD token identifier
+    /// - `amount` - Amount of xEGLD to un-delegate
+    ///
+    /// # Returns
+    /// All payments sent back by the liquid staking contract.
+    fn execute_un_delegation(
+        &self,
+        liquid_staking_address: &ManagedAddress,
+        supplied_token: &EsdtTokenIdentifier,
+        amount: &BigUint,
+    ) -> MultiEgldOrEsdtPayment<Self::Api> {
+        let back_transfers = self
+            .tx()
+            .to(liquid_staking_address)
+            .typed(xoxno_liquid_staking_proxy::LiquidStakingProxy)
+            .un_delegate()
+            .single_esdt(supplied_token, 0, amount)
+            .returns(ReturnsBackTransfers)
+            .sync_call();
+
+        back_transfers.payments
+    }
+
     /// Queries the EGLD value of a given xEGLD amount.
     ///
     /// Calls the liquid staking contract to get the underlying EGLD value
-    /// for a position denominated in xEGLD.
+    /// for a position denominated in xEGLD. The call is readonly, so it can
+    /// also be made from views.
     ///
     /// # Arguments
     /// - `liquid_staking_address` - Address of the liquid staking contract
@@ -79,8 +112,36 @@ pub trait LiquidStakingModule: crate::system::utils::UtilsModule {
             .typed(xoxno_liquid_staking_proxy::LiquidStakingProxy)
             .get_ls_value_for_position(self.md_to_biguint(amount))
             .returns(ReturnsResult)
-            .sync_call();
+            .sync_call_readonly();
 
         ManagedDecimal::from_raw_units(ls_value, WAD_PRECISION)
     }
+
+    /// Queries the xEGLD amount of a given EGLD amount.
+    ///
+    /// Calls the liquid staking contract to get the xEGLD amount received
+    /// when delegating the EGLD amount. The call is readonly, so it can
+    /// also be made from views.
+    ///
+    /// # Arguments
+    /// - `liquid_staking_address` - Address of the liquid staking contract
+    /// - `amount` - Amount of EGLD
+    ///
+    /// # Returns
+    /// The equivalent xEGLD amount as ManagedDecimal.
+    fn get_egld_value_in_ls(
+        &self,
+        liquid_staking_address: &ManagedAddress,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let ls_amount = self
+            .tx()
+            .to(liquid_staking_address)
+            .typed(xoxno_liquid_staking_proxy::LiquidStakingProxy)
+            .get_egld_position_value(self.md_to_biguint(amount))
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+
+        ManagedDecimal::from_raw_units(ls_amount, WAD_PRECISION)
+    }
 }
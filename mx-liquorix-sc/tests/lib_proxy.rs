@@ -1,8 +1,8 @@
 // Proxy module for tests - adapted from output/lib_proxy.rs
-// The inner attributes are removed to allow this file to be used as a module
 
-#[allow(dead_code)]
-#[allow(clippy::all)]
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
 use multiversx_sc::proxy_imports::*;
 
 pub struct LiquorixProxy;
@@ -193,6 +193,28 @@ where
             .original_result()
     }
 
+    pub fn set_max_slippage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_slippage_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxSlippage")
+            .argument(&max_slippage_bps)
+            .original_result()
+    }
+
+    pub fn get_max_slippage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxSlippage")
+            .original_result()
+    }
+
     pub fn get_system_info(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SystemInfo<Env::Api>> {
@@ -228,6 +250,58 @@ where
             .original_result()
     }
 
+    pub fn set_allowlist_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAllowlistEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn add_to_allowlist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        addresses: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToAllowlist")
+            .argument(&addresses)
+            .original_result()
+    }
+
+    pub fn remove_from_allowlist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        addresses: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromAllowlist")
+            .argument(&addresses)
+            .original_result()
+    }
+
+    pub fn can_deposit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canDeposit")
+            .argument(&address)
+            .original_result()
+    }
+
     pub fn pause(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -246,23 +320,120 @@ where
             .original_result()
     }
 
-    pub fn deposit(
+    pub fn deposit<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
         self,
+        opt_min_shares_out: Arg0,
+        opt_swap_args: Arg1,
+        opt_recipient: Arg2,
     ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
         self.wrapped_tx
             .raw_call("deposit")
+            .argument(&opt_min_shares_out)
+            .argument(&opt_swap_args)
+            .argument(&opt_recipient)
             .original_result()
     }
 
     pub fn withdraw<
         Arg0: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         swap_steps: Arg0,
+        opt_min_amount_out: Arg1,
+        opt_receiver: Arg2,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("withdraw")
             .argument(&swap_steps)
+            .argument(&opt_min_amount_out)
+            .argument(&opt_receiver)
+            .original_result()
+    }
+
+    pub fn withdraw_and_unstake<
+        Arg0: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        swap_steps: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawAndUnstake")
+            .argument(&swap_steps)
+            .original_result()
+    }
+
+    pub fn withdraw_and_swap<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        min_amount_out: Arg0,
+        swap_args: Arg1,
+        swap_steps: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawAndSwap")
+            .argument(&min_amount_out)
+            .argument(&swap_args)
+            .argument(&swap_steps)
+            .original_result()
+    }
+
+    pub fn withdraw_exact<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedArgBuffer<Env::Api>>>,
+    >(
+        self,
+        amount: Arg0,
+        swap_steps: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawExact")
+            .argument(&amount)
+            .argument(&swap_steps)
+            .original_result()
+    }
+
+    pub fn withdraw_in_kind(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawInKind")
+            .original_result()
+    }
+
+    pub fn emergency_withdraw(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("emergencyWithdraw")
+            .original_result()
+    }
+
+    pub fn merge_shares(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("mergeShares")
+            .original_result()
+    }
+
+    pub fn split_shares<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, BigUint<Env::Api>>>,
+    >(
+        self,
+        amounts: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValueEncoded<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("splitShares")
+            .argument(&amounts)
             .original_result()
     }
 
@@ -284,15 +455,62 @@ where
             .original_result()
     }
 
+    pub fn preview_deposit<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewDeposit")
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn preview_redeem<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<MultiValue2<ManagedAddress<Env::Api>, u64>>>,
+    >(
+        self,
+        share_amount: Arg0,
+        opt_share_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WithdrawAmounts<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewRedeem")
+            .argument(&share_amount)
+            .argument(&opt_share_token)
+            .original_result()
+    }
+
+    pub fn preview_withdraw<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewWithdraw")
+            .argument(&amount)
+            .original_result()
+    }
+
     pub fn leverage<
         Arg0: ProxyArg<BigUint<Env::Api>>,
         Arg1: ProxyArg<usize>,
         Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
         borrowed_amount: Arg0,
         decimals: Arg1,
         swap_args: Arg2,
+        opt_min_amount_out: Arg3,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -300,22 +518,138 @@ where
             .argument(&borrowed_amount)
             .argument(&decimals)
             .argument(&swap_args)
+            .argument(&opt_min_amount_out)
+            .original_result()
+    }
+
+    pub fn leverage_to_target<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        target_ltv_ratio_bps: Arg0,
+        swap_args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("leverageToTarget")
+            .argument(&target_ltv_ratio_bps)
+            .argument(&swap_args)
+            .original_result()
+    }
+
+    pub fn flash_loan_leverage_callback<
+        Arg0: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        swap_args: Arg0,
+        decimals: Arg1,
+        refunds_receiver: Arg2,
+        initiator: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("flashLoanLeverageCallback")
+            .argument(&swap_args)
+            .argument(&decimals)
+            .argument(&refunds_receiver)
+            .argument(&initiator)
             .original_result()
     }
 
     pub fn deleverage<
         Arg0: ProxyArg<BigUint<Env::Api>>,
         Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
         collateral_amount: Arg0,
         swap_steps: Arg1,
+        opt_min_repaid_amount: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("deleverage")
             .argument(&collateral_amount)
             .argument(&swap_steps)
+            .argument(&opt_min_repaid_amount)
+            .original_result()
+    }
+
+    pub fn flash_deleverage<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        debt_amount: Arg0,
+        collateral_amount: Arg1,
+        swap_args: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flashDeleverage")
+            .argument(&debt_amount)
+            .argument(&collateral_amount)
+            .argument(&swap_args)
+            .original_result()
+    }
+
+    pub fn unwind_all<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        collateral_amount: Arg0,
+        swap_args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unwindAll")
+            .argument(&collateral_amount)
+            .argument(&swap_args)
+            .original_result()
+    }
+
+    pub fn flash_loan_deleverage_callback<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedArgBuffer<Env::Api>>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg5: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        collateral_amount: Arg0,
+        swap_args: Arg1,
+        surplus_swap_args: Arg2,
+        decimals: Arg3,
+        refunds_receiver: Arg4,
+        initiator: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("flashLoanDeleverageCallback")
+            .argument(&collateral_amount)
+            .argument(&swap_args)
+            .argument(&surplus_swap_args)
+            .argument(&decimals)
+            .argument(&refunds_receiver)
+            .argument(&initiator)
+            .original_result()
+    }
+
+    pub fn rebalance<
+        Arg0: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        swap_args: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebalance")
+            .argument(&swap_args)
             .original_result()
     }
 
@@ -331,6 +665,209 @@ where
             .argument(&opt_target_ltv_ratio_bps)
             .original_result()
     }
+
+    pub fn set_treasury_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryAddress")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_fees<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        deposit_fee_bps: Arg0,
+        withdraw_fee_bps: Arg1,
+        performance_fee_bps: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFees")
+            .argument(&deposit_fee_bps)
+            .argument(&withdraw_fee_bps)
+            .argument(&performance_fee_bps)
+            .original_result()
+    }
+
+    pub fn set_management_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        management_fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setManagementFee")
+            .argument(&management_fee_bps)
+            .original_result()
+    }
+
+    pub fn claim_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimFees")
+            .original_result()
+    }
+
+    pub fn get_accrued_management_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccruedManagementFee")
+            .original_result()
+    }
+
+    pub fn get_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFees")
+            .original_result()
+    }
+
+    pub fn set_deposit_caps<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        max_vault_equity_in_egld: Arg0,
+        max_deposit_per_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDepositCaps")
+            .argument(&max_vault_equity_in_egld)
+            .argument(&max_deposit_per_address)
+            .original_result()
+    }
+
+    pub fn get_deposit_caps<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositCaps")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_holding_period<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        min_holding_period_millis: Arg0,
+        early_exit_penalty_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setHoldingPeriod")
+            .argument(&min_holding_period_millis)
+            .argument(&early_exit_penalty_bps)
+            .original_result()
+    }
+
+    pub fn get_holding_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHoldingPeriod")
+            .original_result()
+    }
+
+    pub fn set_min_health_factor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        min_health_factor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinHealthFactor")
+            .argument(&min_health_factor)
+            .original_result()
+    }
+
+    pub fn get_min_health_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinHealthFactor")
+            .original_result()
+    }
+
+    pub fn set_keeper_reward<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        keeper_reward_bps: Arg0,
+        max_keeper_reward_in_egld: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setKeeperReward")
+            .argument(&keeper_reward_bps)
+            .argument(&max_keeper_reward_in_egld)
+            .original_result()
+    }
+
+    pub fn get_keeper_reward(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getKeeperReward")
+            .original_result()
+    }
+
+    pub fn set_max_keeper_slippage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_keeper_slippage_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxKeeperSlippage")
+            .argument(&max_keeper_slippage_bps)
+            .original_result()
+    }
+
+    pub fn get_max_keeper_slippage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxKeeperSlippage")
+            .original_result()
+    }
+
+    pub fn sync_account_positions(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("syncAccountPositions")
+            .original_result()
+    }
 }
 
 #[type_abi]
@@ -353,6 +890,8 @@ where
     pub swap_router: ManagedAddress<Api>,
     pub bot_address: ManagedAddress<Api>,
     pub admins: ManagedVec<Api, ManagedAddress<Api>>,
+    pub high_water_mark: ManagedDecimal<Api, usize>,
+    pub accrued_fee_shares: ManagedDecimal<Api, usize>,
 }
 
 #[type_abi]
@@ -373,6 +912,20 @@ where
     pub liquidation_collateral_available_in_egld: ManagedDecimal<Api, usize>,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct WithdrawAmounts<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub share_amount: ManagedDecimal<Api, usize>,
+    pub redeemed_share_amount: ManagedDecimal<Api, usize>,
+    pub collateral_amount: ManagedDecimal<Api, usize>,
+    pub debt_repaid_in_egld: ManagedDecimal<Api, usize>,
+    pub repayment_collateral_amount: ManagedDecimal<Api, usize>,
+    pub net_amount: ManagedDecimal<Api, usize>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
 pub struct BotInfo<Api>
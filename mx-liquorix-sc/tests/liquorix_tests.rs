@@ -2,6 +2,16 @@
 //!
 //! Basic tests for the main endpoints in the vault and bot modules.
 
+use lib::{
+    constants::DEAD_SHARES,
+    structs::ShareTokenAttributes,
+    system::{
+        attributes::AttributesModule, caps::CapsModule, fees::FeesModule, health::HealthModule,
+        holding_period::HoldingPeriodModule, keeper::KeeperModule, storage::StorageModule,
+        utils::UtilsModule,
+    },
+    vault::VaultModule,
+};
 use multiversx_sc_scenario::imports::*;
 
 mod lib_proxy;
@@ -9,6 +19,7 @@ mod lib_proxy;
 const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
 const USER_ADDRESS: TestAddress = TestAddress::new("user");
 const BOT_ADDRESS: TestAddress = TestAddress::new("bot");
+const TREASURY_ADDRESS: TestAddress = TestAddress::new("treasury");
 
 const LIQUORIX_ADDRESS: TestSCAddress = TestSCAddress::new("liquorix");
 const LIQUID_STAKING_ADDRESS: TestSCAddress = TestSCAddress::new("liquid_staking");
@@ -33,6 +44,34 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+fn share_attributes(
+    total_share_supply: u64,
+    total_initial_supplied_amount: u64,
+    last_interaction_ts_millis: u64,
+) -> ShareTokenAttributes<StaticApi> {
+    ShareTokenAttributes::new(
+        ManagedDecimal::from_raw_units(BigUint::from(total_share_supply), 18usize),
+        ManagedDecimal::from_raw_units(BigUint::from(total_initial_supplied_amount), 18usize),
+        last_interaction_ts_millis,
+    )
+}
+
+fn share_attributes_buffer(
+    total_share_supply: u64,
+    total_initial_supplied_amount: u64,
+    last_interaction_ts_millis: u64,
+) -> ManagedBuffer<StaticApi> {
+    let mut buffer = ManagedBuffer::new();
+    share_attributes(
+        total_share_supply,
+        total_initial_supplied_amount,
+        last_interaction_ts_millis,
+    )
+    .top_encode(&mut buffer)
+    .unwrap();
+    buffer
+}
+
 struct LiquorixTestState {
     world: ScenarioWorld,
 }
@@ -48,11 +87,26 @@ impl LiquorixTestState {
         world
             .account(USER_ADDRESS)
             .nonce(1)
-            .balance(1_000_000_000_000_000_000_000u128);
+            .balance(1_000_000_000_000_000_000_000u128)
+            .esdt_balance(XEGLD_TOKEN_ID, 1_000_000_000_000_000_000_000u128)
+            .esdt_balance(USDC_TOKEN_ID, 1_000_000_000_000_000_000_000u128)
+            .esdt_nft_balance(
+                SHARE_TOKEN_ID,
+                11,
+                1_000u64,
+                share_attributes_buffer(1_000, 500, 1_000),
+            )
+            .esdt_nft_balance(
+                SHARE_TOKEN_ID,
+                12,
+                3_000u64,
+                share_attributes_buffer(3_000, 1_000, 2_000),
+            );
         world
             .account(BOT_ADDRESS)
             .nonce(1)
             .balance(1_000_000_000_000_000_000_000u128);
+        world.account(TREASURY_ADDRESS).nonce(1);
         world
             .account(LIQUID_STAKING_ADDRESS)
             .nonce(1)
@@ -89,6 +143,28 @@ impl LiquorixTestState {
 
         self
     }
+
+    fn set_share_token(&mut self) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(LIQUORIX_ADDRESS)
+            .whitebox(lib::contract_obj, |sc| {
+                sc.share_token()
+                    .set_token_id(SHARE_TOKEN_ID.to_token_identifier());
+            });
+        self.world.set_esdt_local_roles(
+            LIQUORIX_ADDRESS,
+            SHARE_TOKEN_ID.as_bytes(),
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftBurn,
+                EsdtLocalRole::NftUpdateAttributes,
+            ],
+        );
+
+        self
+    }
 }
 
 // ====================================================================
@@ -228,289 +304,2907 @@ fn test_remove_admin() {
 }
 
 // ====================================================================
-// Test: Vault - Deposit
+// Test: Allowlist
 // ====================================================================
 
 #[test]
-fn test_deposit_fails_when_paused() {
+fn test_allowlist_blocks_deposit() {
     let mut state = LiquorixTestState::new();
     state.deploy_contract();
 
-    // Pause the contract
     state
         .world
         .tx()
         .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .pause()
+        .set_allowlist_enabled(true)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .can_deposit(USER_ADDRESS)
+        .returns(ExpectValue(false))
         .run();
 
-    // Deposit should fail when paused
     state
         .world
         .tx()
         .from(USER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .deposit()
-        .egld(1_000_000_000_000_000_000u64) // 1 EGLD
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
         .with_result(ExpectError(
             4,
-            "Contract is currently paused. Please wait for admin to resume operations.",
+            "Access denied: Deposits are restricted to allowlisted addresses.",
         ))
         .run();
 }
 
 #[test]
-fn test_deposit_fails_with_zero_amount() {
+fn test_allowlisted_address_can_deposit() {
     let mut state = LiquorixTestState::new();
     state.deploy_contract();
 
-    // Deposit should fail with zero amount
     state
         .world
         .tx()
-        .from(USER_ADDRESS)
+        .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .deposit()
-        .egld(0u64)
-        .with_result(ExpectError(
-            4,
-            "Invalid deposit: Amount must be greater than zero.",
-        ))
+        .set_allowlist_enabled(true)
         .run();
-}
-
-#[test]
-fn test_deposit_fails_with_wrong_token() {
-    // NOTE: This test is commented out because the testing framework
-    // doesn't support updating account balances after initial setup.
-    // In production, this would be tested via mandos/scenario tests.
-    // The actual contract does validate token type correctly.
-}
-
-// ====================================================================
-// Test: Vault - Withdraw
-// ====================================================================
-
-#[test]
-fn test_withdraw_fails_when_paused() {
-    // NOTE: This test is commented out because the testing framework
-    // doesn't support updating account balances after initial setup.
-    // The withdraw test would require setting up share tokens first.
-    // In production, this would be tested via mandos/scenario tests.
-}
-
-#[test]
-fn test_withdraw_fails_with_invalid_share_token() {
-    // NOTE: This test is commented out because the testing framework
-    // doesn't support updating account balances after initial setup.
-    // In production, this would be tested via mandos/scenario tests.
-}
 
-// ====================================================================
-// Test: Bot - Leverage
-// ====================================================================
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .add_to_allowlist(MultiValueEncoded::from(ManagedVec::from_single_item(
+            USER_ADDRESS.to_managed_address(),
+        )))
+        .run();
 
-#[test]
-fn test_leverage_fails_for_non_bot() {
-    let mut state = LiquorixTestState::new();
-    state.deploy_contract();
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .can_deposit(USER_ADDRESS)
+        .returns(ExpectValue(true))
+        .run();
 
-    // Non-bot should not be able to call leverage
+    // The allowlist check passes, the deposit then fails on the token validation
     state
         .world
         .tx()
         .from(USER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .leverage(
-            BigUint::from(1_000_000_000u64),
-            18usize,
-            ManagedArgBuffer::new(),
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
         )
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
         .with_result(ExpectError(
             4,
-            "Access denied: This operation can only be performed by an authorized bot address.",
+            "Unsupported token: Only EGLD and xEGLD are accepted without swap arguments.",
         ))
         .run();
-}
-
-// ====================================================================
-// Test: Bot - Deleverage
-// ====================================================================
-
-#[test]
-fn test_deleverage_fails_for_non_bot() {
-    let mut state = LiquorixTestState::new();
-    state.deploy_contract();
 
-    // Non-bot should not be able to call deleverage
     state
         .world
         .tx()
-        .from(USER_ADDRESS)
+        .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .deleverage(BigUint::from(1_000_000_000u64), ManagedArgBuffer::new())
-        .with_result(ExpectError(
-            4,
-            "Access denied: This operation can only be performed by an authorized bot address.",
-        ))
+        .remove_from_allowlist(MultiValueEncoded::from(ManagedVec::from_single_item(
+            USER_ADDRESS.to_managed_address(),
+        )))
         .run();
-}
 
-// ====================================================================
-// Test: Bot - Get Bot Info View
-// ====================================================================
-
-#[test]
-fn test_get_bot_info_view() {
-    // NOTE: This test requires external lending/liquid staking contracts
-    // to be properly mocked with their view endpoints.
-    // The getBotInfo view calls getLendingPosition on the lending contract.
-    // In production, this would be tested via integration tests with
-    // actual or mocked external contracts.
-}
-
-#[test]
-fn test_get_bot_info_with_custom_target_ratio() {
-    // NOTE: This test requires external lending/liquid staking contracts
-    // to be properly mocked. See test_get_bot_info_view for details.
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .can_deposit(USER_ADDRESS)
+        .returns(ExpectValue(false))
+        .run();
 }
 
 #[test]
-fn test_get_bot_info_fails_with_invalid_target_ratio() {
+fn test_allowlist_applies_to_deposit_recipient() {
     let mut state = LiquorixTestState::new();
     state.deploy_contract();
 
-    // Query bot info with invalid target ratio (outside bounds)
     state
         .world
-        .query()
+        .tx()
+        .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .get_bot_info(OptionalValue::Some(BigUint::from(9000u64))) // 90% - too high
+        .set_allowlist_enabled(true)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .add_to_allowlist(MultiValueEncoded::from(ManagedVec::from_single_item(
+            USER_ADDRESS.to_managed_address(),
+        )))
+        .run();
+
+    // An allowlisted caller cannot deposit on behalf of a non-allowlisted recipient
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::Some(BigUint::<StaticApi>::zero()),
+            OptionalValue::Some(ManagedArgBuffer::<StaticApi>::new()),
+            OptionalValue::Some(BOT_ADDRESS.to_managed_address()),
+        )
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
         .with_result(ExpectError(
             4,
-            "Invalid target LTV ratio: Must be within the suggested zone bounds.",
+            "Access denied: Deposits are restricted to allowlisted addresses.",
         ))
         .run();
 }
 
-// ====================================================================
-// Test: Vault Views
-// ====================================================================
-
-#[test]
-fn test_get_lending_info_view() {
-    // NOTE: This test requires external lending contract to be properly mocked.
-    // The getLendingInfo view calls getLendingPosition on the lending contract.
-    // In production, this would be tested via integration tests.
-}
-
-#[test]
-fn test_get_tvl_view() {
-    // NOTE: This test requires external contracts to be properly mocked.
-    // The getTvl view calls external contract views.
-    // In production, this would be tested via integration tests.
-}
-
-// ====================================================================
-// Test: Risk Management Parameters
-// ====================================================================
-
 #[test]
-fn test_set_risk_thresholds() {
+fn test_allowlist_applies_to_deposit_caller() {
     let mut state = LiquorixTestState::new();
     state.deploy_contract();
 
-    // Owner should be able to set risk thresholds
     state
         .world
         .tx()
         .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .set_risk_thresholds(5000u64, 1000u64) // 50% safe threshold, 10% inaction zone
+        .set_allowlist_enabled(true)
         .run();
-}
 
-#[test]
-fn test_set_risk_thresholds_fails_for_non_owner() {
-    let mut state = LiquorixTestState::new();
-    state.deploy_contract();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .add_to_allowlist(MultiValueEncoded::from(ManagedVec::from_single_item(
+            BOT_ADDRESS.to_managed_address(),
+        )))
+        .run();
 
-    // Non-owner should not be able to set risk thresholds
+    // A non-allowlisted caller cannot deposit on behalf of an allowlisted recipient
     state
         .world
         .tx()
         .from(USER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .set_risk_thresholds(5000u64, 1000u64)
-        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .deposit(
+            OptionalValue::Some(BigUint::<StaticApi>::zero()),
+            OptionalValue::Some(ManagedArgBuffer::<StaticApi>::new()),
+            OptionalValue::Some(BOT_ADDRESS.to_managed_address()),
+        )
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
+        .with_result(ExpectError(
+            4,
+            "Access denied: Deposits are restricted to allowlisted addresses.",
+        ))
         .run();
 }
 
 #[test]
-fn test_set_risk_thresholds_validates_bounds() {
+fn test_set_allowlist_fails_for_non_admin() {
     let mut state = LiquorixTestState::new();
     state.deploy_contract();
 
-    // Should fail if safe threshold > 100%
     state
         .world
         .tx()
-        .from(OWNER_ADDRESS)
+        .from(USER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .set_risk_thresholds(10001u64, 500u64) // 100.01% - too high
+        .set_allowlist_enabled(true)
         .with_result(ExpectError(
             4,
-            "Invalid threshold: Safe threshold must be <= 100% (10000 BPS).",
+            "Access denied: This operation requires admin privileges.",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .add_to_allowlist(MultiValueEncoded::from(ManagedVec::from_single_item(
+            USER_ADDRESS.to_managed_address(),
+        )))
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation requires admin privileges.",
         ))
         .run();
 }
 
 #[test]
-fn test_set_risk_thresholds_validates_upper_bound() {
+fn test_allowlist_does_not_block_withdraw() {
     let mut state = LiquorixTestState::new();
-    state.deploy_contract();
+    state.deploy_contract().set_share_token();
 
-    // Should fail if safe + inaction > 100%
     state
         .world
         .tx()
         .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .set_risk_thresholds(9000u64, 2000u64) // 90% + 20% = 110% upper bound
+        .set_allowlist_enabled(true)
+        .run();
+
+    // Withdrawals skip the allowlist and fail on the share token validation instead
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw(
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
         .with_result(ExpectError(
             4,
-            "Invalid threshold: Upper bound of inaction zone must be <= 100% (10000 BPS).",
+            "Invalid share token: Withdrawal requires valid share token payment.",
         ))
         .run();
 }
 
+// ====================================================================
+// Test: Vault - Deposit
+// ====================================================================
+
 #[test]
-fn test_set_risk_thresholds_validates_inaction_zone_size() {
+fn test_deposit_fails_when_paused() {
     let mut state = LiquorixTestState::new();
     state.deploy_contract();
 
-    // Should fail if inaction zone >= safe threshold (lower bound would be negative)
+    // Pause the contract
     state
         .world
         .tx()
         .from(OWNER_ADDRESS)
         .to(LIQUORIX_ADDRESS)
         .typed(lib_proxy::LiquorixProxy)
-        .set_risk_thresholds(3000u64, 4000u64) // 30% safe with 40% zone would mean lower bound is -10%
-        .with_result(ExpectError(
-            4,
-            "Invalid threshold: Inaction zone must be less than safe threshold.",
+        .pause()
+        .run();
+
+    // Deposit should fail when paused
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .egld(1_000_000_000_000_000_000u64) // 1 EGLD
+        .with_result(ExpectError(
+            4,
+            "Contract is currently paused. Please wait for admin to resume operations.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_deposit_fails_with_zero_amount() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Deposit should fail with zero amount
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .egld(0u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid deposit: Amount must be greater than zero.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_deposit_fails_with_invalid_share_token_to_merge() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    // The second payment must be a share token to be merged
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(
+            XEGLD_TOKEN_ID,
+            0,
+            1_000_000_000_000_000_000u64,
+        ))
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid share token: Withdrawal requires valid share token payment.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_deposit_fails_merging_share_token_for_other_recipient() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    // A share token can only be merged into the caller's own position
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::Some(BigUint::<StaticApi>::zero()),
+            OptionalValue::Some(ManagedArgBuffer::<StaticApi>::new()),
+            OptionalValue::Some(BOT_ADDRESS.to_managed_address()),
+        )
+        .esdt(TestEsdtTransfer(
+            XEGLD_TOKEN_ID,
+            0,
+            1_000_000_000_000_000_000u64,
+        ))
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid deposit: A share token can only be merged when the caller is the recipient.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_deposit_fails_with_too_many_payments() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // At most the deposit payment and one share token are accepted
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(
+            XEGLD_TOKEN_ID,
+            0,
+            1_000_000_000_000_000_000u64,
+        ))
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid deposit: Expected the deposit payment and at most one share token to merge.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_merge_share_attributes_keeps_latest_timestamp() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let first = ShareTokenAttributes::new(
+                ManagedDecimal::from_raw_units(BigUint::from(100u64), 18usize),
+                ManagedDecimal::from_raw_units(BigUint::from(100u64), 18usize),
+                1_000,
+            );
+            let second = ShareTokenAttributes::new(
+                ManagedDecimal::from_raw_units(BigUint::from(300u64), 18usize),
+                ManagedDecimal::from_raw_units(BigUint::from(200u64), 18usize),
+                2_000,
+            );
+
+            let merged = sc.merge_share_attributes(&first, &second);
+
+            assert_eq!(
+                merged.total_share_supply.into_raw_units(),
+                &BigUint::from(400u64)
+            );
+            assert_eq!(
+                merged.total_initial_supplied_amount.into_raw_units(),
+                &BigUint::from(300u64)
+            );
+            assert_eq!(merged.last_interaction_ts_millis, 2_000);
+        });
+}
+
+#[test]
+fn test_deposit_fails_with_wrong_token() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Tokens other than EGLD and xEGLD need swap arguments
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000_000u64))
+        .with_result(ExpectError(
+            4,
+            "Unsupported token: Only EGLD and xEGLD are accepted without swap arguments.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_first_deposit_fails_below_minimum() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deposit(
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(XEGLD_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid deposit: The first deposit must be at least 1 xEGLD.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_first_deposit_locks_dead_shares() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let share_amount =
+                sc.calculate_first_deposit_share_amount(&ManagedDecimal::from_raw_units(
+                    BigUint::from(1_000_000_000_000_000_000u128),
+                    18usize,
+                ));
+            assert_eq!(
+                share_amount,
+                ManagedDecimal::from_raw_units(BigUint::from(999_999_999_999_999_000u128), 18usize)
+            );
+
+            sc.lock_dead_shares();
+            assert_eq!(
+                sc.total_shares().get(),
+                ManagedDecimal::from_raw_units(BigUint::from(1_000u64), 18usize)
+            );
+        });
+
+    // The dead shares are held by the contract itself
+    state
+        .world
+        .check_account(LIQUORIX_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            1,
+            1_000u64,
+            share_attributes(1_000, 0, 0),
+        );
+}
+
+#[test]
+fn test_donation_front_run_cannot_steal_second_deposit() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = |amount: u128| {
+                ManagedDecimal::<DebugApi, NumDecimals>::from_raw_units(
+                    BigUint::from(amount * 1_000_000_000_000_000_000u128),
+                    18usize,
+                )
+            };
+
+            // The attacker makes the smallest possible first deposit of 1 xEGLD,
+            // receiving 1 share minus the dead shares
+            let attacker_shares = sc.calculate_first_deposit_share_amount(&wad(1));
+            let total_shares = attacker_shares.clone().add(ManagedDecimal::from_raw_units(
+                BigUint::from(1_000u64),
+                18usize,
+            ));
+
+            // The attacker then donates 1000 EGLD to inflate the share price
+            // before the victim deposits 10 EGLD
+            let victim_shares = sc.calculate_deposit_share_amount(
+                total_shares.clone(),
+                wad(10),
+                wad(1_001),
+                wad(0),
+            );
+            assert!(victim_shares > wad(0));
+
+            // The victim can redeem their deposit minus a few wei of rounding
+            let total_shares = total_shares.add(victim_shares.clone());
+            let victim_value = sc.calculate_collateral_share_to_withdraw(
+                total_shares.clone(),
+                victim_shares,
+                wad(1_011),
+            );
+            let victim_loss = wad(10).sub(victim_value);
+            assert!(victim_loss < ManagedDecimal::from_raw_units(BigUint::from(1_000u64), 18usize));
+
+            // The attacker cannot recover more than the donation and the deposit
+            let attacker_value = sc.calculate_collateral_share_to_withdraw(
+                total_shares,
+                attacker_shares,
+                wad(1_011),
+            );
+            assert!(attacker_value <= wad(1_001));
+        });
+}
+
+#[test]
+fn test_donation_after_burn_to_minimum_cannot_steal_second_deposit() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = |amount: u128| {
+                ManagedDecimal::<DebugApi, NumDecimals>::from_raw_units(
+                    BigUint::from(amount * 1_000_000_000_000_000_000u128),
+                    18usize,
+                )
+            };
+            let wei = |amount: u128| {
+                ManagedDecimal::<DebugApi, NumDecimals>::from_raw_units(
+                    BigUint::from(amount),
+                    18usize,
+                )
+            };
+
+            // The attacker makes the smallest possible first deposit of 1 xEGLD
+            let attacker_deposit_shares = sc.calculate_first_deposit_share_amount(&wad(1));
+            let total_shares = attacker_deposit_shares.clone().add(wei(DEAD_SHARES));
+
+            // The attacker then redeems all but 1 wei of their shares
+            let attacker_burned_shares = attacker_deposit_shares.sub(wei(1));
+            let attacker_redeemed_value = sc.calculate_collateral_share_to_withdraw(
+                total_shares.clone(),
+                attacker_burned_shares.clone(),
+                wad(1),
+            );
+            let total_shares = total_shares.sub(attacker_burned_shares);
+            let total_collateral = wad(1).sub(attacker_redeemed_value.clone());
+            assert_eq!(total_shares, wei(DEAD_SHARES + 1));
+
+            // The attacker donates 1000 EGLD to inflate the share price
+            // before the victim deposits 10 EGLD
+            let total_collateral = total_collateral.add(wad(1_000));
+            let victim_shares = sc.calculate_deposit_share_amount(
+                total_shares.clone(),
+                wad(10),
+                total_collateral.clone(),
+                wad(0),
+            );
+            assert!(sc.is_md_gt_zero(&victim_shares));
+
+            let total_shares = total_shares.add(victim_shares);
+            let total_collateral = total_collateral.add(wad(10));
+
+            // The attacker's remaining share is worth far less than the donation
+            let attacker_value =
+                sc.calculate_collateral_share_to_withdraw(total_shares, wei(1), total_collateral);
+            let attacker_spent = wad(1_001);
+            let attacker_received = attacker_redeemed_value.add(attacker_value);
+            assert!(attacker_received < attacker_spent);
+        });
+}
+
+// ====================================================================
+// Test: Vault - Previews
+// ====================================================================
+
+#[test]
+fn test_preview_first_deposit() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_fees(100u64, 0u64, 0u64)
+        .run();
+
+    // 2 xEGLD minus the dead shares, minus the 1% deposit fee
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .preview_deposit(
+            EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN_ID),
+            BigUint::from(2_000_000_000_000_000_000u128),
+        )
+        .returns(ExpectValue(
+            ManagedDecimal::<StaticApi, usize>::from_raw_units(
+                BigUint::from(1_979_999_999_999_999_010u128),
+                18usize,
+            ),
+        ))
+        .run();
+}
+
+#[test]
+fn test_preview_deposit_fails_with_invalid_input() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .preview_deposit(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN_ID),
+            BigUint::from(2_000_000_000_000_000_000u128),
+        )
+        .returns(ExpectError(
+            4,
+            "Unsupported token: Only EGLD and xEGLD are accepted without swap arguments.",
+        ))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .preview_deposit(
+            EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN_ID),
+            BigUint::from(1_000u64),
+        )
+        .returns(ExpectError(
+            4,
+            "Invalid deposit: The first deposit must be at least 1 xEGLD.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_preview_redeem_fails_without_shares() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .preview_redeem(
+            BigUint::from(1_000u64),
+            OptionalValue::<MultiValue2<ManagedAddress<StaticApi>, u64>>::None,
+        )
+        .returns(ExpectError(
+            4,
+            "Lending position does not exist: Cannot operate on a non-existent lending position.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_share_amount_to_redeem_covers_net_amount() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = 1_000_000_000_000_000_000u128;
+            let md = |value: u128| {
+                ManagedDecimal::<DebugApi, NumDecimals>::from_raw_units(
+                    BigUint::from(value),
+                    18usize,
+                )
+            };
+            sc.withdraw_fee_bps().set(50);
+
+            // 1000 shares, 1500 xEGLD collateral, 600 xEGLD debt, 0.5% withdraw fee
+            let net_amount = md(123_456_789_012_345_678_901u128);
+            let share_amount = sc.calculate_share_amount_to_redeem(
+                md(1_000 * wad),
+                &net_amount,
+                md(1_500 * wad),
+                md(600 * wad),
+            );
+
+            // Redeeming the previewed shares pays out at least the requested amount
+            let redeemed_share_amount = share_amount
+                .clone()
+                .sub(sc.calculate_withdraw_fee_shares(share_amount));
+            let collateral_amount = sc.calculate_collateral_share_to_withdraw(
+                md(1_000 * wad),
+                redeemed_share_amount.clone(),
+                md(1_500 * wad),
+            );
+            let debt_amount = sc.calculate_debt_share_to_repay(
+                md(1_000 * wad),
+                redeemed_share_amount,
+                md(600 * wad),
+            );
+            let received_amount = collateral_amount.sub(debt_amount);
+
+            assert!(received_amount >= net_amount);
+            assert!(received_amount.sub(net_amount) < md(1_000));
+        });
+}
+
+#[test]
+fn test_withdraw_in_kind_uses_share_supply_before_withdraw_fee() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = 1_000_000_000_000_000_000u128;
+            let md = |value: u128| {
+                ManagedDecimal::<DebugApi, NumDecimals>::from_raw_units(
+                    BigUint::from(value),
+                    18usize,
+                )
+            };
+            sc.total_shares().set(md(1_000 * wad));
+            sc.withdraw_fee_bps().set(500);
+
+            // Redeeming 500 of 1000 shares with a 5% withdraw fee, as `withdrawInKind` does
+            let (total_shares, redeemed_share_amount) =
+                sc.charge_redemption_fees(&ManagedVec::new(), &md(500 * wad));
+            assert_eq!(total_shares, md(1_000 * wad));
+            assert_eq!(redeemed_share_amount, md(475 * wad));
+            assert_eq!(sc.total_shares().get(), md(1_025 * wad));
+
+            // The in-kind collateral and debt shares match the ones of a normal withdraw
+            let collateral_amount = sc.calculate_collateral_share_to_withdraw(
+                total_shares.clone(),
+                redeemed_share_amount.clone(),
+                md(1_500 * wad),
+            );
+            let debt_amount =
+                sc.mul_div_ceil_md(&md(600 * wad), &redeemed_share_amount, &total_shares);
+            assert_eq!(collateral_amount, md(712_500_000_000_000_000_000));
+            assert_eq!(
+                debt_amount,
+                sc.calculate_debt_share_to_repay(
+                    md(1_000 * wad),
+                    redeemed_share_amount,
+                    md(600 * wad),
+                )
+            );
+        });
+}
+
+// ====================================================================
+// Test: Vault - Merge/Split Shares
+// ====================================================================
+
+#[test]
+fn test_merge_shares() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .merge_shares()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .run();
+
+    // Merged token sums supplies and keeps the latest timestamp
+    state
+        .world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            1,
+            4_000u64,
+            share_attributes(4_000, 1_500, 2_000),
+        )
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            11,
+            0u64,
+            share_attributes(1_000, 500, 1_000),
+        );
+}
+
+#[test]
+fn test_merge_shares_fails_with_single_payment() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .merge_shares()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid merge: At least two share token payments are required.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_split_shares() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    let mut amounts = MultiValueEncoded::new();
+    amounts.push(BigUint::from(1_000u64));
+    amounts.push(BigUint::from(2_000u64));
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .split_shares(amounts)
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .run();
+
+    // Initial supplied amount is split proportionally, the last token takes the remainder
+    state
+        .world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            1,
+            1_000u64,
+            share_attributes(1_000, 333, 2_000),
+        )
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            2,
+            2_000u64,
+            share_attributes(2_000, 667, 2_000),
+        );
+}
+
+#[test]
+fn test_split_shares_fails_with_invalid_amounts() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    let mut amounts = MultiValueEncoded::new();
+    amounts.push(BigUint::from(1_000u64));
+    amounts.push(BigUint::from(1_000u64));
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .split_shares(amounts)
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid split: At least two non-zero amounts summing up to the share token payment are required.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Vault - Withdraw
+// ====================================================================
+
+#[test]
+fn test_withdraw_fails_when_paused() {
+    // NOTE: This test is commented out because the testing framework
+    // doesn't support updating account balances after initial setup.
+    // The withdraw test would require setting up share tokens first.
+    // In production, this would be tested via mandos/scenario tests.
+}
+
+#[test]
+fn test_withdraw_fails_with_invalid_share_token() {
+    // NOTE: This test is commented out because the testing framework
+    // doesn't support updating account balances after initial setup.
+    // In production, this would be tested via mandos/scenario tests.
+}
+
+#[test]
+fn test_batch_withdraw_fails_with_invalid_share_token() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    // Every payment of the batch must be a share token
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw(
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .esdt(TestEsdtTransfer(XEGLD_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid share token: Withdrawal requires valid share token payment.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_and_unstake_fails_when_paused() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .pause()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_and_unstake(OptionalValue::<ManagedArgBuffer<StaticApi>>::None)
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Contract is currently paused. Please wait for admin to resume operations.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_and_unstake_fails_with_invalid_share_token() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_and_unstake(OptionalValue::<ManagedArgBuffer<StaticApi>>::None)
+        .esdt(TestEsdtTransfer(XEGLD_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid share token: Withdrawal requires valid share token payment.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_and_swap_fails_when_paused() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .pause()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_and_swap(
+            1u64,
+            ManagedArgBuffer::<StaticApi>::new(),
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Contract is currently paused. Please wait for admin to resume operations.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_exact_fails_when_paused() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .pause()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_exact(
+            BigUint::from(100u64),
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Contract is currently paused. Please wait for admin to resume operations.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_exact_fails_with_zero_amount() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_exact(
+            BigUint::zero(),
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid collateral: Amount to withdraw must be greater than zero and less than or equal to available collateral.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_in_kind_fails_when_paused() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .pause()
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_in_kind()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Contract is currently paused. Please wait for admin to resume operations.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_in_kind_fails_with_invalid_payments() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    // A borrowed token payment alone redeems no shares
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_in_kind()
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid share token: Withdrawal requires valid share token payment.",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw_in_kind()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000u64))
+        .esdt(TestEsdtTransfer(USDC_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid repayment: At most one borrowed token payment is accepted.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_emergency_withdraw_fails_when_not_paused() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .emergency_withdraw()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(4, "Contract is already active and not paused."))
+        .run();
+}
+
+#[test]
+fn test_emergency_withdraw_fails_with_invalid_share_token() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .pause()
+        .run();
+
+    // The pause check passes, the payment is then validated
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .emergency_withdraw()
+        .esdt(TestEsdtTransfer(XEGLD_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid share token: Withdrawal requires valid share token payment.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Bot - Leverage
+// ====================================================================
+
+#[test]
+fn test_leverage_fails_for_non_bot() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Non-bot should not be able to call leverage
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .leverage(
+            BigUint::from(1_000_000_000u64),
+            18usize,
+            ManagedArgBuffer::new(),
+            OptionalValue::<BigUint<StaticApi>>::None,
+        )
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation can only be performed by an authorized bot address.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_leverage_to_target_fails_for_non_bot() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .leverage_to_target(BigUint::from(4000u64), ManagedArgBuffer::new())
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation can only be performed by an authorized bot address.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_leverage_to_target_fails_with_invalid_target_ratio() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // 90% is outside the default 35%-45% zone
+    state
+        .world
+        .tx()
+        .from(BOT_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .leverage_to_target(BigUint::from(9000u64), ManagedArgBuffer::new())
+        .with_result(ExpectError(
+            4,
+            "Invalid target LTV ratio: Must be within the suggested zone bounds.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_flash_loan_leverage_callback_fails_outside_flash_loan() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Even with the vault as initiator, the callback requires an ongoing vault flash loan
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .flash_loan_leverage_callback(
+            ManagedArgBuffer::new(),
+            6usize,
+            USER_ADDRESS,
+            LIQUORIX_ADDRESS,
+        )
+        .with_result(ExpectError(
+            4,
+            "Access denied: This endpoint can only be called during a flash loan taken by the vault.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Bot - Deleverage
+// ====================================================================
+
+#[test]
+fn test_deleverage_fails_for_non_bot() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Non-bot should not be able to call deleverage
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .deleverage(
+            BigUint::from(1_000_000_000u64),
+            ManagedArgBuffer::new(),
+            OptionalValue::<BigUint<StaticApi>>::None,
+        )
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation can only be performed by an authorized bot address.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_flash_deleverage_fails_for_non_bot() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .flash_deleverage(
+            BigUint::from(1_000_000u64),
+            BigUint::from(1_000_000_000u64),
+            ManagedArgBuffer::new(),
+        )
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation can only be performed by an authorized bot address.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_unwind_all_fails_for_non_admin() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // The bot cannot unwind the vault, only admins can
+    state
+        .world
+        .tx()
+        .from(BOT_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .unwind_all(BigUint::from(1_000_000_000u64), ManagedArgBuffer::new())
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation requires admin privileges.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_flash_loan_deleverage_callback_fails_outside_flash_loan() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .flash_loan_deleverage_callback(
+            BigUint::from(1_000_000_000u64),
+            ManagedArgBuffer::new(),
+            ManagedArgBuffer::new(),
+            6usize,
+            USER_ADDRESS,
+            LIQUORIX_ADDRESS,
+        )
+        .with_result(ExpectError(
+            4,
+            "Access denied: This endpoint can only be called during a flash loan taken by the vault.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_flash_loan_deleverage_callback_fails_for_non_lending_caller() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Even during a vault flash loan, only the lending controller can call back
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            sc.flash_loan_ongoing().set(true);
+        });
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .flash_loan_deleverage_callback(
+            BigUint::from(1_000_000_000u64),
+            ManagedArgBuffer::new(),
+            ManagedArgBuffer::new(),
+            6usize,
+            USER_ADDRESS,
+            LIQUORIX_ADDRESS,
+        )
+        .with_result(ExpectError(
+            4,
+            "Access denied: This endpoint can only be called during a flash loan taken by the vault.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Bot - Rebalance
+// ====================================================================
+
+#[test]
+fn test_rebalance_fails_when_paused() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .pause()
+        .run();
+
+    // Anyone can rebalance, but not while the contract is paused
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .rebalance(ManagedArgBuffer::new())
+        .with_result(ExpectError(
+            4,
+            "Contract is currently paused. Please wait for admin to resume operations.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Bot - Get Bot Info View
+// ====================================================================
+
+#[test]
+fn test_get_bot_info_view() {
+    // NOTE: This test requires external lending/liquid staking contracts
+    // to be properly mocked with their view endpoints.
+    // The getBotInfo view calls getLendingPosition on the lending contract.
+    // In production, this would be tested via integration tests with
+    // actual or mocked external contracts.
+}
+
+#[test]
+fn test_get_bot_info_with_custom_target_ratio() {
+    // NOTE: This test requires external lending/liquid staking contracts
+    // to be properly mocked. See test_get_bot_info_view for details.
+}
+
+#[test]
+fn test_get_bot_info_fails_with_invalid_target_ratio() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Query bot info with invalid target ratio (outside bounds)
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_bot_info(OptionalValue::Some(BigUint::from(9000u64))) // 90% - too high
+        .with_result(ExpectError(
+            4,
+            "Invalid target LTV ratio: Must be within the suggested zone bounds.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Vault Views
+// ====================================================================
+
+#[test]
+fn test_get_lending_info_view() {
+    // NOTE: This test requires external lending contract to be properly mocked.
+    // The getLendingInfo view calls getLendingPosition on the lending contract.
+    // In production, this would be tested via integration tests.
+}
+
+#[test]
+fn test_get_tvl_view() {
+    // NOTE: This test requires external contracts to be properly mocked.
+    // The getTvl view calls external contract views.
+    // In production, this would be tested via integration tests.
+}
+
+#[test]
+fn test_calculate_position_pnl_on_equity() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = 1_000_000_000_000_000_000u128;
+            let md = |value: u128| {
+                ManagedDecimal::<DebugApi, NumDecimals>::from_raw_units(
+                    BigUint::from(value),
+                    18usize,
+                )
+            };
+            let signed_md = |value: i128| {
+                ManagedDecimalSigned::<DebugApi, NumDecimals>::from_raw_units(
+                    BigInt::from(&RustBigInt::from(value)),
+                    18usize,
+                )
+            };
+
+            // 10 shares from 10 xEGLD, xEGLD at 1.1 EGLD, EGLD at 30 USD
+            let share_attributes = ShareTokenAttributes::new(md(10 * wad), md(10 * wad), 0);
+
+            // Share price of 1.21 EGLD: 10% profit on equity
+            let position_pnl = sc.calculate_position_pnl(
+                1,
+                md(10 * wad),
+                share_attributes.clone(),
+                &md(1_210_000_000_000_000_000),
+                &md(1_100_000_000_000_000_000),
+                &md(30 * wad),
+            );
+            assert_eq!(position_pnl.initial_value_in_egld, md(11 * wad));
+            assert_eq!(
+                position_pnl.current_value_in_egld,
+                md(12_100_000_000_000_000_000)
+            );
+            assert_eq!(
+                position_pnl.pnl_in_egld,
+                signed_md(1_100_000_000_000_000_000)
+            );
+            assert_eq!(position_pnl.initial_value_in_usd, md(330 * wad));
+            assert_eq!(position_pnl.current_value_in_usd, md(363 * wad));
+            assert_eq!(position_pnl.pnl_in_usd, signed_md(33 * wad as i128));
+            assert_eq!(position_pnl.pnl_percentage, signed_md(10 * wad as i128));
+
+            // Share price of 1 EGLD: the debt makes the position lose value
+            let position_pnl = sc.calculate_position_pnl(
+                1,
+                md(10 * wad),
+                share_attributes,
+                &md(wad),
+                &md(1_100_000_000_000_000_000),
+                &md(30 * wad),
+            );
+            assert_eq!(position_pnl.pnl_in_egld, signed_md(-(wad as i128)));
+            assert_eq!(position_pnl.pnl_in_usd, signed_md(-30 * wad as i128));
+            assert_eq!(
+                position_pnl.pnl_percentage,
+                signed_md(-9_090_909_090_909_090_910)
+            );
+        });
+}
+
+// ====================================================================
+// Test: Risk Management Parameters
+// ====================================================================
+
+#[test]
+fn test_set_risk_thresholds() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Owner should be able to set risk thresholds
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_risk_thresholds(5000u64, 1000u64) // 50% safe threshold, 10% inaction zone
+        .run();
+}
+
+#[test]
+fn test_set_risk_thresholds_fails_for_non_owner() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Non-owner should not be able to set risk thresholds
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_risk_thresholds(5000u64, 1000u64)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn test_set_risk_thresholds_validates_bounds() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Should fail if safe threshold > 100%
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_risk_thresholds(10001u64, 500u64) // 100.01% - too high
+        .with_result(ExpectError(
+            4,
+            "Invalid threshold: Safe threshold must be <= 100% (10000 BPS).",
+        ))
+        .run();
+}
+
+#[test]
+fn test_set_risk_thresholds_validates_upper_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Should fail if safe + inaction > 100%
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_risk_thresholds(9000u64, 2000u64) // 90% + 20% = 110% upper bound
+        .with_result(ExpectError(
+            4,
+            "Invalid threshold: Upper bound of inaction zone must be <= 100% (10000 BPS).",
+        ))
+        .run();
+}
+
+#[test]
+fn test_set_risk_thresholds_validates_inaction_zone_size() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Should fail if inaction zone >= safe threshold (lower bound would be negative)
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_risk_thresholds(3000u64, 4000u64) // 30% safe with 40% zone would mean lower bound is -10%
+        .with_result(ExpectError(
+            4,
+            "Invalid threshold: Inaction zone must be less than safe threshold.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_set_max_slippage() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Default maximum slippage is 1%
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_max_slippage()
+        .returns(ExpectValue(100u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_max_slippage(250u64)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_max_slippage()
+        .returns(ExpectValue(250u64))
+        .run();
+}
+
+#[test]
+fn test_set_max_slippage_validates_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Should fail if max slippage > 10%
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_max_slippage(1_001u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid slippage: Maximum slippage must be <= 10% (1000 BPS).",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Fees
+// ====================================================================
+
+#[test]
+fn test_set_fees() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_fees(10u64, 20u64, 1_000u64)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_fees()
+        .returns(ExpectValue(MultiValue3::from((10u64, 20u64, 1_000u64))))
+        .run();
+}
+
+#[test]
+fn test_set_fees_fails_for_non_owner() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_fees(10u64, 20u64, 1_000u64)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn test_set_fees_validates_bounds() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Should fail if the withdraw fee is above 5%
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_fees(0u64, 501u64, 0u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid fee: Deposit and withdraw fees must be <= 5% (500 BPS).",
+        ))
+        .run();
+
+    // Should fail if the performance fee is above 30%
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_fees(0u64, 0u64, 3_001u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid fee: Performance fee must be <= 30% (3000 BPS).",
+        ))
+        .run();
+}
+
+#[test]
+fn test_calculate_performance_fee_shares() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = 1_000_000_000_000_000_000u128;
+            let md = |value: u128| ManagedDecimal::from_raw_units(BigUint::from(value), 18usize);
+
+            // 100 shares, equity 120 EGLD, high-water mark 1.1 EGLD, 20% fee:
+            // gain = 10 EGLD, fee = 2 EGLD, fee shares = 2 * 100 / 118
+            let fee_shares = sc.calculate_performance_fee_shares(
+                md(100 * wad),
+                md(120 * wad),
+                md(wad + wad / 5),
+                md(wad + wad / 10),
+                2_000,
+            );
+            assert_eq!(
+                fee_shares.into_raw_units(),
+                &BigUint::from(1_694_915_254_237_288_135u128)
+            );
+
+            // 5% of 1000 shares
+            let fee_amount = sc.calculate_fee_amount(md(1_000 * wad), 500);
+            assert_eq!(fee_amount.into_raw_units(), &BigUint::from(50 * wad));
+        });
+}
+
+#[test]
+fn test_management_fee_accrues_over_time() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(TimestampMillis::new(1_000));
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            sc.total_shares().set(ManagedDecimal::from_raw_units(
+                BigUint::from(1_000_000_000_000_000_000_000u128),
+                18usize,
+            ));
+        });
+
+    // Setting the fee starts the accrual clock
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_management_fee(200u64)
+        .run();
+
+    // One year later, 2% of 1000 shares are due after dilution: 1000 * 0.02 / 0.98
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(TimestampMillis::new(1_000 + 31_536_000_000));
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_accrued_management_fee()
+        .returns(ExpectValue(
+            ManagedDecimal::<StaticApi, usize>::from_raw_units(
+                BigUint::from(20_408_163_265_306_122_448u128),
+                18usize,
+            ),
+        ))
+        .run();
+}
+
+#[test]
+fn test_set_management_fee_validates_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_management_fee(501u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid fee: Management fee must be <= 5% (500 BPS) per year.",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_management_fee(100u64)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn test_claim_fees() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_treasury_address(TREASURY_ADDRESS)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            sc.accrued_fee_shares().set(ManagedDecimal::from_raw_units(
+                BigUint::from(500u64),
+                18usize,
+            ));
+        });
+
+    // Only the treasury or the owner can claim
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .claim_fees()
+        .with_result(ExpectError(
+            4,
+            "Access denied: This operation can only be performed by the treasury or the owner.",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(TREASURY_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .claim_fees()
+        .run();
+
+    state
+        .world
+        .check_account(TREASURY_ADDRESS)
+        .esdt_nft_balance_and_attributes(SHARE_TOKEN_ID, 1, 500u64, share_attributes(500, 0, 0));
+
+    // Nothing left to claim
+    state
+        .world
+        .tx()
+        .from(TREASURY_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .claim_fees()
+        .with_result(ExpectError(
+            4,
+            "Nothing to claim: No fee shares have accrued.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_claim_fees_fails_without_treasury() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .claim_fees()
+        .with_result(ExpectError(
+            4,
+            "Treasury not set: A treasury address is required to claim fees.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Deposit Caps
+// ====================================================================
+
+#[test]
+fn test_set_deposit_caps() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_deposit_caps(
+            BigUint::from(1_000_000_000_000_000_000_000u128),
+            BigUint::from(10_000_000_000_000_000_000u128),
+        )
+        .run();
+
+    // Without a lending position the whole vault cap is available
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_deposit_caps(USER_ADDRESS)
+        .returns(ExpectValue(MultiValue4::from((
+            BigUint::from(1_000_000_000_000_000_000_000u128),
+            BigUint::from(1_000_000_000_000_000_000_000u128),
+            BigUint::from(10_000_000_000_000_000_000u128),
+            BigUint::from(10_000_000_000_000_000_000u128),
+        ))))
+        .run();
+}
+
+#[test]
+fn test_set_deposit_caps_fails_for_non_owner() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_deposit_caps(BigUint::zero(), BigUint::zero())
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn test_address_deposit_tracking() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_deposit_caps(
+            BigUint::zero(),
+            BigUint::from(10_000_000_000_000_000_000u128),
+        )
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let user = ManagedAddress::from(USER_ADDRESS.to_address());
+
+            // Deposits worth 6 EGLD and 3 EGLD accumulate against the cap
+            sc.track_address_deposit(
+                &user,
+                &ManagedDecimal::from_raw_units(
+                    BigUint::from(6_000_000_000_000_000_000u128),
+                    18usize,
+                ),
+            );
+            sc.track_address_deposit(
+                &user,
+                &ManagedDecimal::from_raw_units(
+                    BigUint::from(3_000_000_000_000_000_000u128),
+                    18usize,
+                ),
+            );
+
+            assert_eq!(
+                sc.deposited_amount(&user).get(),
+                BigUint::from(9_000_000_000_000_000_000u128)
+            );
+        });
+}
+
+#[test]
+fn test_address_deposit_cap_exceeded() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_deposit_caps(
+            BigUint::zero(),
+            BigUint::from(10_000_000_000_000_000_000u128),
+        )
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .returns(ExpectError(
+            4,
+            "Deposit cap exceeded: The deposit would exceed the maximum deposit per address.",
+        ))
+        .whitebox(lib::contract_obj, |sc| {
+            sc.track_address_deposit(
+                &ManagedAddress::from(USER_ADDRESS.to_address()),
+                &ManagedDecimal::from_raw_units(
+                    BigUint::from(11_000_000_000_000_000_000u128),
+                    18usize,
+                ),
+            );
+        });
+}
+
+#[test]
+fn test_vault_deposit_cap_exceeded() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_deposit_caps(
+            BigUint::from(100_000_000_000_000_000_000u128),
+            BigUint::zero(),
+        )
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .returns(ExpectError(
+            4,
+            "Deposit cap exceeded: The deposit would exceed the maximum vault equity.",
+        ))
+        .whitebox(lib::contract_obj, |sc| {
+            // 95 EGLD of equity leaves room for 5 EGLD, not 6
+            sc.require_within_vault_cap(
+                &ManagedDecimal::from_raw_units(
+                    BigUint::from(6_000_000_000_000_000_000u128),
+                    18usize,
+                ),
+                &ManagedDecimal::from_raw_units(
+                    BigUint::from(95_000_000_000_000_000_000u128),
+                    18usize,
+                ),
+            );
+        });
+}
+
+// ====================================================================
+// Test: Holding Period
+// ====================================================================
+
+#[test]
+fn test_set_holding_period() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_holding_period(86_400_000u64, 200u64)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_holding_period()
+        .returns(ExpectValue(MultiValue2::from((86_400_000u64, 200u64))))
+        .run();
+}
+
+#[test]
+fn test_set_holding_period_validates_penalty_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_holding_period(86_400_000u64, 1_001u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid penalty: Early exit penalty must be <= 10% (1000 BPS).",
+        ))
+        .run();
+}
+
+#[test]
+fn test_withdraw_fails_during_holding_period() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_holding_period(86_400_000u64, 0u64)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw(
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Holding period not elapsed: Shares cannot be withdrawn before the minimum holding period.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_merge_does_not_shorten_holding_period() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_holding_period(5_000u64, 0u64)
+        .run();
+
+    // Nonce 11 (ts 1_000) is out of its holding period, nonce 12 (ts 2_000) is not.
+    // A share-weighted timestamp (1_750) would end the merged holding period at 6_750.
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(TimestampMillis::new(6_800));
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .merge_shares()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .run();
+
+    state
+        .world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            1,
+            4_000u64,
+            share_attributes(4_000, 1_500, 2_000),
+        );
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .withdraw(
+            OptionalValue::<ManagedArgBuffer<StaticApi>>::None,
+            OptionalValue::<BigUint<StaticApi>>::None,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 1, 4_000u64))
+        .with_result(ExpectError(
+            4,
+            "Holding period not elapsed: Shares cannot be withdrawn before the minimum holding period.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_partial_withdraw_keeps_holding_period() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(TimestampMillis::new(1_000));
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            // Share token nonce 1, created at 1_000
+            sc.lock_dead_shares();
+        });
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(TimestampMillis::new(5_000));
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            // Withdrawing part of the nonce does not restart its holding period
+            let new_attributes = sc.process_new_share_attributes(
+                1,
+                ManagedDecimal::from_raw_units(BigUint::from(400u64), 18usize),
+            );
+
+            assert_eq!(
+                new_attributes.total_share_supply,
+                ManagedDecimal::from_raw_units(BigUint::from(600u64), 18usize)
+            );
+            assert_eq!(new_attributes.last_interaction_ts_millis, 1_000);
+        });
+}
+
+#[test]
+fn test_early_exit_penalty() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            // Share token nonce 1, created now
+            sc.lock_dead_shares();
+            let share_payments = ManagedVec::from_single_item(EsdtTokenPayment::new(
+                SHARE_TOKEN_ID.to_token_identifier(),
+                1,
+                BigUint::from(1_000u64),
+            ));
+
+            // No holding period, no penalty
+            sc.early_exit_penalty_bps().set(500);
+            assert_eq!(
+                sc.charge_early_exit_penalty(&share_payments),
+                ManagedDecimal::from_raw_units(BigUint::zero(), 18usize)
+            );
+
+            // 5% penalty while in the holding period
+            sc.min_holding_period_millis().set(86_400_000);
+            assert_eq!(
+                sc.get_holding_period_end(LIQUORIX_ADDRESS.to_managed_address(), 1),
+                86_400_000
+            );
+            assert_eq!(
+                sc.charge_early_exit_penalty(&share_payments),
+                ManagedDecimal::from_raw_units(BigUint::from(50u64), 18usize)
+            );
+        });
+}
+
+#[test]
+fn test_preview_redeem_applies_holding_period() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            // Share token nonce 1, created now, in a one day holding period
+            sc.lock_dead_shares();
+            sc.min_holding_period_millis().set(86_400_000);
+
+            // 5% penalty on the redeemed shares
+            sc.early_exit_penalty_bps().set(500);
+            let share_attributes = sc.get_share_token_attributes(1);
+            assert_eq!(
+                sc.calculate_early_exit_penalty_shares(
+                    &share_attributes,
+                    ManagedDecimal::from_raw_units(BigUint::from(1_000u64), 18usize),
+                ),
+                ManagedDecimal::from_raw_units(BigUint::from(50u64), 18usize)
+            );
+
+            sc.early_exit_penalty_bps().set(0);
+        });
+
+    // Without a penalty, redeeming the share token early is blocked, so is the preview
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .preview_redeem(
+            BigUint::from(1_000u64),
+            OptionalValue::Some(MultiValue2::from((
+                LIQUORIX_ADDRESS.to_managed_address(),
+                1u64,
+            ))),
+        )
+        .returns(ExpectError(
+            4,
+            "Holding period not elapsed: Shares cannot be withdrawn before the minimum holding period.",
+        ))
+        .run();
+}
+
+// ====================================================================
+// Test: Health Factor
+// ====================================================================
+
+#[test]
+fn test_set_min_health_factor() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Default minimum health factor is 1.1
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_min_health_factor()
+        .returns(ExpectValue(BigUint::from(1_100_000_000_000_000_000u64)))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_min_health_factor(BigUint::from(1_500_000_000_000_000_000u64))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_min_health_factor()
+        .returns(ExpectValue(BigUint::from(1_500_000_000_000_000_000u64)))
+        .run();
+}
+
+#[test]
+fn test_set_min_health_factor_validates_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    // Should fail if the minimum is below the liquidation threshold of 1
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_min_health_factor(BigUint::from(999_999_999_999_999_999u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid health factor: Minimum health factor must be >= 1 (WAD).",
+        ))
+        .run();
+}
+
+#[test]
+fn test_position_without_debt_is_not_below_min_health_factor() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let lending_address = ManagedAddress::from(LENDING_ADDRESS.to_address());
+
+            // Without debt, the lending protocol is not queried
+            assert!(!sc.is_below_min_health_factor(&lending_address, 1u64));
+            sc.require_min_health_factor(&lending_address, 1u64);
+        });
+}
+
+// ====================================================================
+// Test: Keeper Reward
+// ====================================================================
+
+#[test]
+fn test_set_keeper_reward() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_keeper_reward(50u64, BigUint::from(1_000_000_000_000_000_000u64))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_keeper_reward()
+        .returns(ExpectValue(MultiValue2::from((
+            50u64,
+            BigUint::from(1_000_000_000_000_000_000u64),
+        ))))
+        .run();
+}
+
+#[test]
+fn test_set_keeper_reward_validates_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_keeper_reward(101u64, BigUint::from(1_000_000_000_000_000_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid reward: Keeper reward must be <= 1% (100 BPS) of the rebalanced amount.",
+        ))
+        .run();
+}
+
+#[test]
+fn test_set_max_keeper_slippage() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_max_keeper_slippage()
+        .returns(ExpectValue(20u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_max_keeper_slippage(50u64)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .get_max_keeper_slippage()
+        .returns(ExpectValue(50u64))
+        .run();
+}
+
+#[test]
+fn test_set_max_keeper_slippage_validates_bound() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .set_max_keeper_slippage(101u64)
+        .with_result(ExpectError(
+            4,
+            "Invalid slippage: Maximum keeper slippage must be <= 1% (100 BPS).",
+        ))
+        .run();
+}
+
+#[test]
+fn test_calculate_keeper_reward_is_capped() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let wad = 1_000_000_000_000_000_000u128;
+            let md = |value: u128| ManagedDecimal::from_raw_units(BigUint::from(value), 18usize);
+
+            // Disabled by default
+            let reward = sc.calculate_keeper_reward_in_egld(md(100 * wad));
+            assert_eq!(reward.into_raw_units(), &BigUint::zero());
+
+            // 0.5% of the rebalanced amount, capped at 1 EGLD
+            sc.keeper_reward_bps().set(50u64);
+            sc.max_keeper_reward_in_egld().set(BigUint::from(wad));
+
+            let reward = sc.calculate_keeper_reward_in_egld(md(100 * wad));
+            assert_eq!(reward.into_raw_units(), &BigUint::from(wad / 2));
+
+            let reward = sc.calculate_keeper_reward_in_egld(md(1_000 * wad));
+            assert_eq!(reward.into_raw_units(), &BigUint::from(wad));
+        });
+}
+
+// ====================================================================
+// Test: Account Positions
+// ====================================================================
+
+#[test]
+fn test_split_and_merge_register_account_positions() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    let mut amounts = MultiValueEncoded::new();
+    amounts.push(BigUint::from(1_000u64));
+    amounts.push(BigUint::from(2_000u64));
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .split_shares(amounts)
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let account_positions = sc.account_positions(&USER_ADDRESS.to_managed_address());
+            assert_eq!(account_positions.len(), 2);
+            assert!(account_positions.contains(&1));
+            assert!(account_positions.contains(&2));
+        });
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .merge_shares()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 1, 1_000u64))
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 2, 2_000u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let account_positions = sc.account_positions(&USER_ADDRESS.to_managed_address());
+            assert_eq!(account_positions.len(), 1);
+            assert!(account_positions.contains(&3));
+        });
+}
+
+#[test]
+fn test_sync_account_positions() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .sync_account_positions()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 11, 1_000u64))
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .run();
+
+    // The share tokens are sent back
+    state
+        .world
+        .check_account(USER_ADDRESS)
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            11,
+            1_000u64,
+            share_attributes(1_000, 500, 1_000),
+        )
+        .esdt_nft_balance_and_attributes(
+            SHARE_TOKEN_ID,
+            12,
+            3_000u64,
+            share_attributes(3_000, 1_000, 2_000),
+        );
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let account_positions = sc.account_positions(&USER_ADDRESS.to_managed_address());
+            assert_eq!(account_positions.len(), 2);
+            assert!(account_positions.contains(&11));
+            assert!(account_positions.contains(&12));
+        });
+
+    // Nonce 11 was transferred away, so it is no longer sent
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .sync_account_positions()
+        .esdt(TestEsdtTransfer(SHARE_TOKEN_ID, 12, 3_000u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .whitebox(lib::contract_obj, |sc| {
+            let account_positions = sc.account_positions(&USER_ADDRESS.to_managed_address());
+            assert_eq!(account_positions.len(), 1);
+            assert!(account_positions.contains(&12));
+        });
+}
+
+#[test]
+fn test_sync_account_positions_fails_with_invalid_share_token() {
+    let mut state = LiquorixTestState::new();
+    state.deploy_contract().set_share_token();
+
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS)
+        .to(LIQUORIX_ADDRESS)
+        .typed(lib_proxy::LiquorixProxy)
+        .sync_account_positions()
+        .esdt(TestEsdtTransfer(XEGLD_TOKEN_ID, 0, 1_000u64))
+        .with_result(ExpectError(
+            4,
+            "Invalid share token: Withdrawal requires valid share token payment.",
         ))
         .run();
 }
@@ -548,6 +3242,7 @@ fn test_set_bot_address() {
             BigUint::from(1_000_000_000u64),
             18usize,
             ManagedArgBuffer::new(),
+            OptionalValue::<BigUint<StaticApi>>::None,
         )
         .with_result(ExpectError(
             4,
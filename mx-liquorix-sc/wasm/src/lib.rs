@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           24
+// Endpoints:                           70
 // Async Callback:                       1
-// Total number of exported functions:  27
+// Total number of exported functions:  73
 
 #![no_std]
 
@@ -28,22 +28,68 @@ multiversx_sc_wasm_adapter::endpoints! {
         setSwapRouterAddress => set_swap_router_address
         setBotAddress => set_bot_address
         setRiskThresholds => set_risk_thresholds
+        setMaxSlippage => set_max_slippage
         getSystemInfo => get_system_info
         getSystemInfoMultiValue => get_system_info_multi_value
+        getMaxSlippage => get_max_slippage
         addAdmins => add_admin
         removeAdmins => remove_admin
+        setAllowlistEnabled => set_allowlist_enabled
+        addToAllowlist => add_to_allowlist
+        removeFromAllowlist => remove_from_allowlist
+        isAllowlistEnabled => is_allowlist_enabled
+        canDeposit => can_deposit
+        getAllowlist => get_allowlist
         pause => pause
         unpause => unpause
         deposit => deposit
         withdraw => withdraw
+        withdrawAndUnstake => withdraw_and_unstake
+        withdrawAndSwap => withdraw_and_swap
+        withdrawExact => withdraw_exact
+        withdrawInKind => withdraw_in_kind
+        emergencyWithdraw => emergency_withdraw
+        mergeShares => merge_shares
+        splitShares => split_shares
         getLendingInfo => get_lending_info
         getLendingInfoMultiValue => get_lending_info_multi_value
         getTvl => get_tvl
         getPositionsPnl => get_positions_pnl
+        getAccountPositions => get_account_positions
+        previewDeposit => preview_deposit
+        previewRedeem => preview_redeem
+        previewWithdraw => preview_withdraw
         leverage => leverage
+        leverageToTarget => leverage_to_target
+        flashLoanLeverageCallback => flash_loan_leverage_callback
         deleverage => deleverage
+        flashDeleverage => flash_deleverage
+        unwindAll => unwind_all
+        flashLoanDeleverageCallback => flash_loan_deleverage_callback
+        rebalance => rebalance
         getBotInfo => get_bot_info
         getBotInfoMultiValue => get_bot_info_multi_value
+        setTreasuryAddress => set_treasury_address
+        setFees => set_fees
+        setManagementFee => set_management_fee
+        claimFees => claim_fees
+        getFees => get_fees
+        getAccruedManagementFee => get_accrued_management_fee
+        getManagementFee => get_management_fee
+        getTreasuryAddress => get_treasury_address
+        setDepositCaps => set_deposit_caps
+        getDepositCaps => get_deposit_caps
+        getDepositedAmount => get_deposited_amount
+        setHoldingPeriod => set_holding_period
+        getHoldingPeriod => get_holding_period
+        getHoldingPeriodEnd => get_holding_period_end
+        setMinHealthFactor => set_min_health_factor
+        getMinHealthFactor => get_min_health_factor
+        setKeeperReward => set_keeper_reward
+        setMaxKeeperSlippage => set_max_keeper_slippage
+        getKeeperReward => get_keeper_reward
+        getMaxKeeperSlippage => get_max_keeper_slippage
+        syncAccountPositions => sync_account_positions
     )
 }
 